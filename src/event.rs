@@ -43,6 +43,7 @@ use std::path::PathBuf;
 use crate::{
   dpi::{PhysicalPosition, PhysicalSize},
   keyboard::{self, ModifiersState},
+  monitor::MonitorHandle,
   platform_impl,
   window::{Theme, WindowId},
 };
@@ -300,6 +301,16 @@ pub enum WindowEvent<'a> {
   /// The window received a unicode character.
   ReceivedImeText(String),
 
+  /// An event from an input method.
+  ///
+  /// Text committed through an input method is still reported with [`WindowEvent::ReceivedImeText`]
+  /// as well, so this only needs to be handled to render the in-progress composition.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS / Android:** Unsupported.
+  Ime(ImeEvent),
+
   /// The window gained or lost focus.
   ///
   /// The parameter is true if the window has gained focus, and false if it has lost focus.
@@ -422,6 +433,24 @@ pub enum WindowEvent<'a> {
   ///
   /// - **Linux / macOS / Android / iOS:** Unsupported
   DecorationsClick,
+
+  /// The window has been fully hidden behind other windows, or has become visible again.
+  ///
+  /// Applications can use this to pause rendering while the window is not visible.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / Linux / Android / iOS:** Occlusion isn't tracked, this is emitted once with
+  ///   [`OcclusionState::Visible`] when the window is created.
+  OcclusionStateChanged(OcclusionState),
+
+  /// The window has moved to a different monitor, either because it was dragged there or because
+  /// the display configuration changed.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android / iOS:** Unsupported
+  MonitorChanged { new_monitor: MonitorHandle },
 }
 
 impl Clone for WindowEvent<'static> {
@@ -436,6 +465,7 @@ impl Clone for WindowEvent<'static> {
       HoveredFile(file) => HoveredFile(file.clone()),
       HoveredFileCancelled => HoveredFileCancelled,
       ReceivedImeText(c) => ReceivedImeText(c.clone()),
+      Ime(ime) => Ime(ime.clone()),
       Focused(f) => Focused(*f),
       KeyboardInput {
         device_id,
@@ -512,6 +542,10 @@ impl Clone for WindowEvent<'static> {
         unreachable!("Static event can't be about scale factor changing")
       }
       DecorationsClick => DecorationsClick,
+      OcclusionStateChanged(state) => OcclusionStateChanged(*state),
+      MonitorChanged { new_monitor } => MonitorChanged {
+        new_monitor: new_monitor.clone(),
+      },
     };
   }
 }
@@ -528,6 +562,7 @@ impl<'a> WindowEvent<'a> {
       HoveredFile(file) => Some(HoveredFile(file)),
       HoveredFileCancelled => Some(HoveredFileCancelled),
       ReceivedImeText(c) => Some(ReceivedImeText(c)),
+      Ime(ime) => Some(Ime(ime)),
       Focused(focused) => Some(Focused(focused)),
       KeyboardInput {
         device_id,
@@ -597,6 +632,8 @@ impl<'a> WindowEvent<'a> {
       ThemeChanged(theme) => Some(ThemeChanged(theme)),
       ScaleFactorChanged { .. } => None,
       DecorationsClick => Some(DecorationsClick),
+      OcclusionStateChanged(state) => Some(OcclusionStateChanged(state)),
+      MonitorChanged { new_monitor } => Some(MonitorChanged { new_monitor }),
     }
   }
 }
@@ -781,6 +818,39 @@ impl KeyEvent {
   }
 }
 
+/// Describes an event from an input method editor (IME).
+///
+/// A composition session is always started with [`ImeEvent::Enabled`], followed by any number of
+/// [`ImeEvent::Preedit`] updates, an optional [`ImeEvent::Commit`] and finally [`ImeEvent::Disabled`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ImeEvent {
+  /// A composition session has started.
+  Enabled,
+  /// The text being composed has changed.
+  ///
+  /// Contains the pre-edit text and the cursor range inside it as byte offsets. The cursor range
+  /// is `None` when the cursor should be hidden. An empty string means the pre-edit text
+  /// should be cleared.
+  Preedit(String, Option<(usize, usize)>),
+  /// The composition has been committed, contains the final text.
+  Commit(String),
+  /// The composition session has ended.
+  Disabled,
+}
+
+/// Describes whether a window is visible on screen, see [`WindowEvent::OcclusionStateChanged`].
+#[non_exhaustive]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OcclusionState {
+  /// At least part of the window is visible.
+  Visible,
+  /// The window is fully hidden, e.g. behind other windows or on another space.
+  Hidden,
+}
+
 /// Describes touch-screen input state.
 #[non_exhaustive]
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
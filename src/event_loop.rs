@@ -14,7 +14,13 @@
 //! [event_loop_proxy]: crate::event_loop::EventLoopProxy
 //! [send_event]: crate::event_loop::EventLoopProxy::send_event
 use instant::Instant;
-use std::{error, fmt, marker::PhantomData, ops::Deref};
+use std::{
+  cell::{Cell, RefCell},
+  error, fmt,
+  marker::PhantomData,
+  ops::Deref,
+  rc::Rc,
+};
 
 use crate::{
   dpi::PhysicalPosition,
@@ -52,9 +58,25 @@ pub struct EventLoop<T: 'static> {
 #[derive(Clone)]
 pub struct EventLoopWindowTarget<T: 'static> {
   pub(crate) p: platform_impl::EventLoopWindowTarget<T>,
+  pub(crate) event_filter: Rc<RefCell<Option<EventFilterFn<T>>>>,
+  pub(crate) idle_callback: Rc<IdleCallback>,
   pub(crate) _marker: ::std::marker::PhantomData<*mut ()>, // Not Send nor Sync
 }
 
+pub(crate) type EventFilterFn<T> = Box<dyn FnMut(&Event<'_, T>) -> EventFilter>;
+
+/// State of the callback set with [`EventLoopWindowTarget::set_idle_callback`].
+#[derive(Default)]
+pub(crate) struct IdleCallback {
+  callback: RefCell<Option<Box<dyn FnMut()>>>,
+  // The control flow replaced by `Poll` after the callback ran, restored once an iteration
+  // brings no new events.
+  replaced_control_flow: Cell<Option<ControlFlow>>,
+  // Whether an event other than the loop's own lifecycle events was received since the last
+  // `MainEventsCleared`.
+  received_events: Cell<bool>,
+}
+
 impl<T> fmt::Debug for EventLoop<T> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     f.pad("EventLoop { .. }")
@@ -160,6 +182,19 @@ pub enum ControlFlow {
   ExitWithCode(i32),
 }
 
+/// Hint for how precisely [`ControlFlow::WaitUntil`] should wake up the event loop.
+///
+/// See [`EventLoopWindowTarget::set_control_flow_precision`].
+#[non_exhaustive]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ControlFlowPrecision {
+  /// Wake up as close as possible to the requested time.
+  #[default]
+  HighResolution,
+  /// Let the OS delay the wake up a little to coalesce it with other timers, which saves power.
+  LowPower,
+}
+
 impl ControlFlow {
   /// Alias for [`ExitWithCode`]`(0)`.
   ///
@@ -208,14 +243,19 @@ impl<T> EventLoop<T> {
   ///
   /// [`ControlFlow`]: crate::event_loop::ControlFlow
   #[inline]
-  pub fn run<F>(self, event_handler: F) -> !
+  pub fn run<F>(self, mut event_handler: F) -> !
   where
     F: 'static + FnMut(Event<'_, T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
   {
-    self.event_loop.run(event_handler)
+    self.event_loop.run(move |event, target, control_flow| {
+      target.dispatch_event(event, control_flow, &mut event_handler)
+    })
   }
 
   /// Creates an `EventLoopProxy` that can be used to dispatch user events to the main event loop.
+  ///
+  /// The proxy is `Send` and `Sync` as long as `T` is `Send`, so it can be shared across threads
+  /// behind an `Arc` or cloned for each of them.
   pub fn create_proxy(&self) -> EventLoopProxy<T> {
     EventLoopProxy {
       event_loop_proxy: self.event_loop.create_proxy(),
@@ -243,7 +283,8 @@ impl<T> EventLoopWindowTarget<T> {
 
   /// Returns the primary monitor of the system.
   ///
-  /// Returns `None` if it can't identify any monitor as a primary one.
+  /// Returns `None` if it can't identify any monitor as a primary one, e.g. when no monitor is
+  /// attached.
   #[inline]
   pub fn primary_monitor(&self) -> Option<MonitorHandle> {
     self.p.primary_monitor()
@@ -319,6 +360,187 @@ impl<T> EventLoopWindowTarget<T> {
     ))]
     self.p.set_theme(theme)
   }
+
+  /// Requests a redraw of every window created by this event loop, as if
+  /// [`Window::request_redraw`](crate::window::Window::request_redraw) was called on each of them.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS / Android:** Unsupported.
+  #[inline]
+  pub fn request_redraw_all(&self) {
+    #[cfg(any(
+      windows,
+      target_os = "linux",
+      target_os = "dragonfly",
+      target_os = "freebsd",
+      target_os = "netbsd",
+      target_os = "openbsd",
+      target_os = "macos",
+    ))]
+    self.p.request_redraw_all()
+  }
+
+  /// Sets how precisely [`ControlFlow::WaitUntil`] wakes up the event loop.
+  ///
+  /// The default is [`ControlFlowPrecision::HighResolution`].
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** `HighResolution` uses a high resolution waitable timer, which requires
+  ///   Windows 10 version 1803 or later. Older versions and `LowPower` are bound to the system
+  ///   timer resolution, usually around 15ms.
+  /// - **Linux / iOS / Android:** Unsupported.
+  #[inline]
+  pub fn set_control_flow_precision(&self, _precision: ControlFlowPrecision) {
+    #[cfg(any(windows, target_os = "macos"))]
+    self.p.set_control_flow_precision(_precision)
+  }
+
+  /// Sets a filter that is called with every event before it reaches the event handler.
+  ///
+  /// Events for which the filter returns [`EventFilter::Consume`] are dropped and never reach
+  /// the event handler. This replaces any previously set filter.
+  ///
+  /// The filter runs from within the event loop, so it must not call [`set_event_filter`] or
+  /// [`clear_event_filter`] itself, doing so panics. Events dispatched by a nested run loop
+  /// started from within the filter (e.g. by a modal OS operation) bypass the filter.
+  ///
+  /// ```no_run
+  /// use tao::{
+  ///   event::{Event, WindowEvent},
+  ///   event_loop::{EventFilter, EventLoop},
+  /// };
+  ///
+  /// let event_loop = EventLoop::new();
+  /// event_loop.set_event_filter(|event| match event {
+  ///   Event::WindowEvent {
+  ///     event: WindowEvent::KeyboardInput { .. },
+  ///     ..
+  ///   } => EventFilter::Consume,
+  ///   _ => EventFilter::Keep,
+  /// });
+  /// ```
+  ///
+  /// [`set_event_filter`]: Self::set_event_filter
+  /// [`clear_event_filter`]: Self::clear_event_filter
+  #[inline]
+  pub fn set_event_filter<F>(&self, filter: F)
+  where
+    F: 'static + FnMut(&Event<'_, T>) -> EventFilter,
+  {
+    *self.event_filter.borrow_mut() = Some(Box::new(filter));
+  }
+
+  /// Removes the filter set by [`set_event_filter`](Self::set_event_filter).
+  #[inline]
+  pub fn clear_event_filter(&self) {
+    *self.event_filter.borrow_mut() = None;
+  }
+
+  /// Sets a callback that is called every time the event loop runs out of events to process,
+  /// right after [`Event::MainEventsCleared`] was handled. This replaces any previously set
+  /// callback.
+  ///
+  /// While the event handler uses [`ControlFlow::Wait`], the event loop switches to
+  /// [`ControlFlow::Poll`] after the callback ran. If the next iteration brings new events, e.g.
+  /// sent by the callback through an [`EventLoopProxy`], the callback runs again once they were
+  /// handled. Otherwise the event loop goes back to [`ControlFlow::Wait`] without calling the
+  /// callback, until other events wake it up. This avoids running the whole application with
+  /// [`ControlFlow::Poll`] just to make progress on background work.
+  ///
+  /// The callback must not call [`set_idle_callback`] or [`clear_idle_callback`] itself, doing
+  /// so panics.
+  ///
+  /// [`set_idle_callback`]: Self::set_idle_callback
+  /// [`clear_idle_callback`]: Self::clear_idle_callback
+  #[inline]
+  pub fn set_idle_callback<F>(&self, callback: F)
+  where
+    F: 'static + FnMut(),
+  {
+    *self.idle_callback.callback.borrow_mut() = Some(Box::new(callback));
+  }
+
+  /// Removes the callback set by [`set_idle_callback`](Self::set_idle_callback).
+  #[inline]
+  pub fn clear_idle_callback(&self) {
+    *self.idle_callback.callback.borrow_mut() = None;
+  }
+
+  /// Runs the event filter and the idle callback around `event_handler`.
+  pub(crate) fn dispatch_event<F>(
+    &self,
+    event: Event<'_, T>,
+    control_flow: &mut ControlFlow,
+    event_handler: &mut F,
+  ) where
+    F: FnMut(Event<'_, T>, &EventLoopWindowTarget<T>, &mut ControlFlow),
+  {
+    let main_events_cleared = matches!(event, Event::MainEventsCleared);
+    if !matches!(
+      event,
+      Event::NewEvents(_)
+        | Event::MainEventsCleared
+        | Event::RedrawRequested(_)
+        | Event::RedrawEventsCleared
+        | Event::LoopDestroyed
+    ) {
+      self.idle_callback.received_events.set(true);
+    }
+
+    if self.filter_event(&event) == EventFilter::Keep {
+      event_handler(event, self, control_flow);
+    }
+
+    if main_events_cleared {
+      self.run_idle_callback(control_flow);
+    }
+  }
+
+  fn run_idle_callback(&self, control_flow: &mut ControlFlow) {
+    let idle = &self.idle_callback;
+    let received_events = idle.received_events.replace(false);
+
+    // The previous run didn't lead to new events, go back to waiting.
+    if let Some(replaced) = idle.replaced_control_flow.take() {
+      if !received_events {
+        if *control_flow == ControlFlow::Poll {
+          *control_flow = replaced;
+        }
+        return;
+      }
+    }
+
+    // Skipped if the idle callback itself is pumping the event loop.
+    if let Ok(mut callback) = idle.callback.try_borrow_mut() {
+      if let Some(callback) = callback.as_mut() {
+        callback();
+        if *control_flow == ControlFlow::Wait {
+          idle.replaced_control_flow.set(Some(ControlFlow::Wait));
+          *control_flow = ControlFlow::Poll;
+        }
+      }
+    }
+  }
+
+  fn filter_event(&self, event: &Event<'_, T>) -> EventFilter {
+    match self.event_filter.try_borrow_mut() {
+      Ok(mut filter) => apply_event_filter(&mut filter, event),
+      // The filter itself is pumping the event loop.
+      Err(_) => EventFilter::Keep,
+    }
+  }
+}
+
+/// Decides whether `event` reaches the event handler, every event is kept when no filter is set.
+fn apply_event_filter<T>(
+  filter: &mut Option<EventFilterFn<T>>,
+  event: &Event<'_, T>,
+) -> EventFilter {
+  filter
+    .as_mut()
+    .map_or(EventFilter::Keep, |filter| filter(event))
 }
 
 #[cfg(feature = "rwh_05")]
@@ -352,6 +574,8 @@ impl<T> rwh_06::HasDisplayHandle for EventLoopWindowTarget<T> {
 }
 
 /// Used to send custom events to `EventLoop`.
+///
+/// This is `Send` and `Sync` when `T` is `Send`, see [`EventLoop::create_proxy`].
 pub struct EventLoopProxy<T: 'static> {
   event_loop_proxy: platform_impl::EventLoopProxy<T>,
 }
@@ -394,6 +618,15 @@ impl<T> fmt::Display for EventLoopClosed<T> {
 
 impl<T: fmt::Debug> error::Error for EventLoopClosed<T> {}
 
+/// Returned by the filter set with [`EventLoopWindowTarget::set_event_filter`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum EventFilter {
+  /// Pass the event on to the event handler.
+  Keep,
+  /// Drop the event, the event handler won't receive it.
+  Consume,
+}
+
 /// Fiter controlling the propagation of device events.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum DeviceEventFilter {
@@ -410,3 +643,67 @@ impl Default for DeviceEventFilter {
     Self::Unfocused
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    event::{DeviceId, ElementState, KeyEvent, WindowEvent},
+    keyboard::{Key, KeyCode, KeyLocation},
+    window::WindowId,
+  };
+
+  fn keyboard_input() -> Event<'static, u32> {
+    Event::WindowEvent {
+      window_id: unsafe { WindowId::dummy() },
+      event: WindowEvent::KeyboardInput {
+        device_id: unsafe { DeviceId::dummy() },
+        event: KeyEvent {
+          physical_key: KeyCode::KeyA,
+          logical_key: Key::Character("a"),
+          text: Some("a"),
+          location: KeyLocation::Standard,
+          state: ElementState::Pressed,
+          repeat: false,
+          #[cfg(not(any(target_os = "android", target_os = "ios")))]
+          platform_specific: platform_impl::KeyEventExtra {
+            text_with_all_modifiers: Some("a"),
+            key_without_modifiers: Key::Character("a"),
+          },
+          #[cfg(any(target_os = "android", target_os = "ios"))]
+          platform_specific: platform_impl::KeyEventExtra {},
+        },
+        is_synthetic: false,
+      },
+    }
+  }
+
+  #[test]
+  fn filter_consuming_keyboard_input_keeps_other_events() {
+    let mut filter: Option<EventFilterFn<u32>> = Some(Box::new(|event| match event {
+      Event::WindowEvent {
+        event: WindowEvent::KeyboardInput { .. },
+        ..
+      } => EventFilter::Consume,
+      _ => EventFilter::Keep,
+    }));
+
+    let mut handled = Vec::new();
+    let mut event_handler = |event: Event<'static, u32>| handled.push(event);
+    for event in [keyboard_input(), Event::UserEvent(1)] {
+      if apply_event_filter(&mut filter, &event) == EventFilter::Keep {
+        event_handler(event);
+      }
+    }
+
+    assert_eq!(handled, vec![Event::UserEvent(1)]);
+  }
+
+  #[test]
+  fn no_filter_keeps_every_event() {
+    assert_eq!(
+      apply_event_filter(&mut None, &keyboard_input()),
+      EventFilter::Keep
+    );
+  }
+}
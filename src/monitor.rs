@@ -119,9 +119,16 @@ pub struct MonitorHandle {
 }
 
 impl MonitorHandle {
-  /// Returns a human-readable name of the monitor.
+  /// Returns a human-readable name of the monitor, such as the product name reported by the
+  /// display.
   ///
-  /// Returns `None` if the monitor doesn't exist anymore.
+  /// Returns `None` if the monitor doesn't exist anymore or the platform can't provide a name.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Falls back to the device name, e.g. `\\.\DISPLAY1`, when the monitor has no
+  ///   friendly name.
+  /// - **macOS:** Requires macOS 10.15+, returns `None` on older versions.
   #[inline]
   pub fn name(&self) -> Option<String> {
     self.inner.name()
@@ -152,6 +159,50 @@ impl MonitorHandle {
     self.inner.scale_factor()
   }
 
+  /// Returns the physical dimensions of the monitor in millimetres, as `(width, height)`.
+  ///
+  /// Returns `(0, 0)` if the size is unknown, which is common for projectors and some virtual
+  /// displays. The value is reported by the monitor's EDID and may be inaccurate.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS / Android:** Always returns `(0, 0)`.
+  #[inline]
+  pub fn physical_size_mm(&self) -> (u32, u32) {
+    self.inner.physical_size_mm()
+  }
+
+  /// Returns the pixel density of the monitor in pixels per inch, computed from [`Self::size`]
+  /// and [`Self::physical_size_mm`] along the diagonal.
+  ///
+  /// Returns `0.0` if the physical size is unknown.
+  pub fn dpi_estimate(&self) -> f64 {
+    let (width_mm, height_mm) = self.physical_size_mm();
+    if width_mm == 0 || height_mm == 0 {
+      return 0.0;
+    }
+
+    let size = self.size();
+    let diagonal_px = (size.width as f64).hypot(size.height as f64);
+    let diagonal_in = (width_mm as f64).hypot(height_mm as f64) / 25.4;
+    diagonal_px / diagonal_in
+  }
+
+  /// Returns the monitor's current refresh rate in millihertz.
+  ///
+  /// Unlike [`VideoMode::refresh_rate`], this is the rate the monitor is running at right now and
+  /// keeps the fractional part, e.g. `59940` for a 59.94 Hz display.
+  ///
+  /// Returns `None` if the refresh rate can't be determined.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Android:** Always returns `None`.
+  #[inline]
+  pub fn refresh_rate_millihertz(&self) -> Option<u32> {
+    self.inner.refresh_rate_millihertz()
+  }
+
   /// Returns all fullscreen video modes supported by this monitor.
   ///
   /// ## Platform-specific
@@ -17,6 +17,7 @@ use crate::{
 use cocoa::appkit::{
   NSApplicationActivationPolicy, NSApplicationActivationPolicyAccessory,
   NSApplicationActivationPolicyProhibited, NSApplicationActivationPolicyRegular,
+  NSVisualEffectMaterial, NSWindowTabbingMode,
 };
 
 /// Additional methods on `Window` that are specific to MacOS.
@@ -43,6 +44,13 @@ pub trait WindowExtMacOS {
   /// space or taking control over the entire monitor.
   fn set_simple_fullscreen(&self, fullscreen: bool) -> bool;
 
+  /// Toggles native fullscreen with the system animation, like the green fullscreen button.
+  ///
+  /// Unlike [`Window::set_fullscreen`], the window is resized at the end of the animation, so
+  /// [`WindowEvent::Resized`](crate::event::WindowEvent::Resized) is emitted once the transition
+  /// completes. Does nothing while the window is in exclusive fullscreen.
+  fn toggle_fullscreen_animated(&self);
+
   /// Returns whether or not the window has shadow.
   fn has_shadow(&self) -> bool;
 
@@ -61,6 +69,10 @@ pub trait WindowExtMacOS {
 
   /// Sets whether the system can automatically organize windows into tabs.
   ///
+  /// `allowsAutomaticWindowTabbing` is a class property, so this affects every window created
+  /// afterwards. This window's `tabbingMode` is also set to `disallowed` when `enabled` is
+  /// `false`, or back to `automatic` otherwise, so it is not merged into a tab group either.
+  ///
   /// <https://developer.apple.com/documentation/appkit/nswindow/1646657-allowsautomaticwindowtabbing>
   fn set_allows_automatic_window_tabbing(&self, enabled: bool);
 
@@ -75,6 +87,11 @@ pub trait WindowExtMacOS {
   /// Returns the window's tabbing identifier.
   fn tabbing_identifier(&self) -> String;
 
+  /// Merges all windows of the application into tabs of this window's group.
+  ///
+  /// <https://developer.apple.com/documentation/appkit/nswindow/1644639-mergeallwindows>
+  fn merge_all_windows(&self);
+
   /// The content view consumes the full size of the window.
   ///
   /// <https://developer.apple.com/documentation/appkit/nsfullsizecontentviewwindowmask>
@@ -84,6 +101,41 @@ pub trait WindowExtMacOS {
   ///
   /// <https://developer.apple.com/documentation/appkit/nswindow/1419167-titlebarappearstransparent>
   fn set_titlebar_transparent(&self, transparent: bool);
+
+  /// Sets whether the window is excluded from the application's Windows menu.
+  ///
+  /// Useful for helper windows such as HUDs or popovers.
+  ///
+  /// <https://developer.apple.com/documentation/appkit/nswindow/1419175-excludedfromwindowsmenu>
+  fn set_excluded_from_windows_menu(&self, excluded: bool);
+
+  /// Returns whether the window is excluded from the application's Windows menu.
+  fn is_excluded_from_windows_menu(&self) -> bool;
+
+  /// Sets the name used to automatically save the window's frame in the user defaults.
+  ///
+  /// The frame is saved whenever the window moves or resizes. Call
+  /// [`restore_frame_using_name`](WindowExtMacOS::restore_frame_using_name) to restore it.
+  ///
+  /// <https://developer.apple.com/documentation/appkit/nswindow/1419426-setframeautosavename>
+  fn set_frame_autosave_name(&self, name: &str);
+
+  /// Restores the window's frame previously saved under `name`.
+  ///
+  /// Returns `false` if no frame was saved under that name.
+  ///
+  /// <https://developer.apple.com/documentation/appkit/nswindow/1428612-setframeusingname>
+  fn restore_frame_using_name(&self, name: &str) -> bool;
+
+  /// Adds an `NSVisualEffectView` with the given material behind the window content, or removes
+  /// it when `None`.
+  ///
+  /// The effect blends with what is behind the window, so the window must be created with
+  /// [`WindowBuilder::with_transparent`] for it to be visible. It follows the window's
+  /// active state.
+  ///
+  /// <https://developer.apple.com/documentation/appkit/nsvisualeffectview>
+  fn set_visual_effect(&self, material: Option<VibrancyMaterial>);
 }
 
 impl WindowExtMacOS for Window {
@@ -107,6 +159,11 @@ impl WindowExtMacOS for Window {
     self.window.set_simple_fullscreen(fullscreen)
   }
 
+  #[inline]
+  fn toggle_fullscreen_animated(&self) {
+    self.window.toggle_fullscreen_animated()
+  }
+
   #[inline]
   fn has_shadow(&self) -> bool {
     self.window.has_shadow()
@@ -152,6 +209,11 @@ impl WindowExtMacOS for Window {
     self.window.tabbing_identifier()
   }
 
+  #[inline]
+  fn merge_all_windows(&self) {
+    self.window.merge_all_windows()
+  }
+
   #[inline]
   fn set_fullsize_content_view(&self, fullsize: bool) {
     self.window.set_fullsize_content_view(fullsize);
@@ -161,6 +223,120 @@ impl WindowExtMacOS for Window {
   fn set_titlebar_transparent(&self, transparent: bool) {
     self.window.set_titlebar_transparent(transparent);
   }
+
+  #[inline]
+  fn set_excluded_from_windows_menu(&self, excluded: bool) {
+    self.window.set_excluded_from_windows_menu(excluded);
+  }
+
+  #[inline]
+  fn is_excluded_from_windows_menu(&self) -> bool {
+    self.window.is_excluded_from_windows_menu()
+  }
+
+  #[inline]
+  fn set_frame_autosave_name(&self, name: &str) {
+    self.window.set_frame_autosave_name(name);
+  }
+
+  #[inline]
+  fn restore_frame_using_name(&self, name: &str) -> bool {
+    self.window.restore_frame_using_name(name)
+  }
+
+  #[inline]
+  fn set_visual_effect(&self, material: Option<VibrancyMaterial>) {
+    self.window.set_visual_effect(material);
+  }
+}
+
+/// The style of the titlebar, see [`WindowBuilderExtMacOS::with_title_bar_style`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TitleBarStyle {
+  /// The regular titlebar.
+  #[default]
+  Visible,
+  /// The titlebar is transparent and the content extends behind it. The title stays visible.
+  Transparent,
+  /// Only the traffic light buttons are shown, on top of the content.
+  ///
+  /// The buttons keep working and can be moved with
+  /// [`WindowBuilderExtMacOS::with_traffic_light_inset`].
+  Overlay,
+}
+
+/// Corresponds to `NSVisualEffectMaterial`, see [`WindowExtMacOS::set_visual_effect`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VibrancyMaterial {
+  Titlebar,
+  Selection,
+  Menu,
+  Popover,
+  Sidebar,
+  /// Available on macOS 10.14 and later.
+  HeaderView,
+  /// Available on macOS 10.14 and later.
+  Sheet,
+  /// Available on macOS 10.14 and later.
+  WindowBackground,
+  /// Available on macOS 10.14 and later.
+  HudWindow,
+  /// Available on macOS 10.14 and later.
+  FullScreenUI,
+  /// Available on macOS 10.14 and later.
+  Tooltip,
+  /// Available on macOS 10.14 and later.
+  ContentBackground,
+  /// Available on macOS 10.14 and later.
+  UnderWindowBackground,
+  /// Available on macOS 10.14 and later.
+  UnderPageBackground,
+}
+
+impl From<VibrancyMaterial> for NSVisualEffectMaterial {
+  fn from(material: VibrancyMaterial) -> Self {
+    match material {
+      VibrancyMaterial::Titlebar => NSVisualEffectMaterial::Titlebar,
+      VibrancyMaterial::Selection => NSVisualEffectMaterial::Selection,
+      VibrancyMaterial::Menu => NSVisualEffectMaterial::Menu,
+      VibrancyMaterial::Popover => NSVisualEffectMaterial::Popover,
+      VibrancyMaterial::Sidebar => NSVisualEffectMaterial::Sidebar,
+      VibrancyMaterial::HeaderView => NSVisualEffectMaterial::HeaderView,
+      VibrancyMaterial::Sheet => NSVisualEffectMaterial::Sheet,
+      VibrancyMaterial::WindowBackground => NSVisualEffectMaterial::WindowBackground,
+      VibrancyMaterial::HudWindow => NSVisualEffectMaterial::HudWindow,
+      VibrancyMaterial::FullScreenUI => NSVisualEffectMaterial::FullScreenUI,
+      VibrancyMaterial::Tooltip => NSVisualEffectMaterial::Tooltip,
+      VibrancyMaterial::ContentBackground => NSVisualEffectMaterial::ContentBackground,
+      VibrancyMaterial::UnderWindowBackground => NSVisualEffectMaterial::UnderWindowBackground,
+      VibrancyMaterial::UnderPageBackground => NSVisualEffectMaterial::UnderPageBackground,
+    }
+  }
+}
+
+/// Corresponds to `NSWindowTabbingMode`, see [`WindowBuilderExtMacOS::with_tabbing_mode`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabbingMode {
+  /// The window is tabbed depending on the user's preference.
+  #[default]
+  Automatic,
+  /// The window is always opened as a tab.
+  Preferred,
+  /// The window is never tabbed.
+  Disallowed,
+}
+
+impl From<TabbingMode> for NSWindowTabbingMode {
+  fn from(mode: TabbingMode) -> Self {
+    match mode {
+      TabbingMode::Automatic => NSWindowTabbingMode::NSWindowTabbingModeAutomatic,
+      TabbingMode::Preferred => NSWindowTabbingMode::NSWindowTabbingModePreferred,
+      TabbingMode::Disallowed => NSWindowTabbingMode::NSWindowTabbingModeDisallowed,
+    }
+  }
 }
 
 /// Corresponds to `NSApplicationActivationPolicy`.
@@ -201,6 +377,7 @@ impl From<ActivationPolicy> for NSApplicationActivationPolicy {
 ///  - `with_titlebar_hidden`
 ///  - `with_titlebar_buttons_hidden`
 ///  - `with_fullsize_content_view`
+///  - `with_title_bar_style`
 pub trait WindowBuilderExtMacOS {
   /// Sets a parent to the window to be created.
   fn with_parent_window(self, parent: *mut c_void) -> WindowBuilder;
@@ -216,7 +393,11 @@ pub trait WindowBuilderExtMacOS {
   fn with_titlebar_buttons_hidden(self, titlebar_buttons_hidden: bool) -> WindowBuilder;
   /// Makes the window content appear behind the titlebar.
   fn with_fullsize_content_view(self, fullsize_content_view: bool) -> WindowBuilder;
+  /// Sets the titlebar transparency, title visibility and full-size content view together.
+  fn with_title_bar_style(self, style: TitleBarStyle) -> WindowBuilder;
   /// Build window with `resizeIncrements` property. Values must not be 0.
+  ///
+  /// [`WindowBuilder::with_resize_increments`] takes precedence over this.
   fn with_resize_increments(self, increments: LogicalSize<f64>) -> WindowBuilder;
   fn with_disallow_hidpi(self, disallow_hidpi: bool) -> WindowBuilder;
   /// Sets whether or not the window has shadow.
@@ -229,6 +410,12 @@ pub trait WindowBuilderExtMacOS {
   ///
   /// [tabbing identifier]: <https://developer.apple.com/documentation/appkit/nswindow/1644704-tabbingidentifier>
   fn with_tabbing_identifier(self, identifier: &str) -> WindowBuilder;
+  /// Sets the window [tabbing mode].
+  ///
+  /// [tabbing mode]: <https://developer.apple.com/documentation/appkit/nswindow/1644729-tabbingmode>
+  fn with_tabbing_mode(self, mode: TabbingMode) -> WindowBuilder;
+  /// Adds a visual effect view behind the window content, see [`WindowExtMacOS::set_visual_effect`].
+  fn with_visual_effect(self, material: Option<VibrancyMaterial>) -> WindowBuilder;
 }
 
 impl WindowBuilderExtMacOS for WindowBuilder {
@@ -289,6 +476,19 @@ impl WindowBuilderExtMacOS for WindowBuilder {
     self
   }
 
+  #[inline]
+  fn with_title_bar_style(mut self, style: TitleBarStyle) -> WindowBuilder {
+    let (transparent, title_hidden) = match style {
+      TitleBarStyle::Visible => (false, false),
+      TitleBarStyle::Transparent => (true, false),
+      TitleBarStyle::Overlay => (true, true),
+    };
+    self.platform_specific.titlebar_transparent = transparent;
+    self.platform_specific.title_hidden = title_hidden;
+    self.platform_specific.fullsize_content_view = transparent;
+    self
+  }
+
   #[inline]
   fn with_has_shadow(mut self, has_shadow: bool) -> WindowBuilder {
     self.platform_specific.has_shadow = has_shadow;
@@ -315,6 +515,18 @@ impl WindowBuilderExtMacOS for WindowBuilder {
       .replace(tabbing_identifier.into());
     self
   }
+
+  #[inline]
+  fn with_tabbing_mode(mut self, mode: TabbingMode) -> WindowBuilder {
+    self.platform_specific.tabbing_mode = Some(mode);
+    self
+  }
+
+  #[inline]
+  fn with_visual_effect(mut self, material: Option<VibrancyMaterial>) -> WindowBuilder {
+    self.platform_specific.visual_effect = material;
+    self
+  }
 }
 
 pub trait EventLoopExtMacOS {
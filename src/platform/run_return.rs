@@ -41,10 +41,12 @@ pub trait EventLoopExtRunReturn {
 impl<T> EventLoopExtRunReturn for EventLoop<T> {
   type UserEvent = T;
 
-  fn run_return<F>(&mut self, event_handler: F) -> i32
+  fn run_return<F>(&mut self, mut event_handler: F) -> i32
   where
     F: FnMut(Event<'_, Self::UserEvent>, &EventLoopWindowTarget<Self::UserEvent>, &mut ControlFlow),
   {
-    self.event_loop.run_return(event_handler)
+    self.event_loop.run_return(|event, target, control_flow| {
+      target.dispatch_event(event, control_flow, &mut event_handler)
+    })
   }
 }
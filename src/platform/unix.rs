@@ -12,6 +12,8 @@
 
 use std::{os::raw::c_int, sync::Arc};
 
+use gtk::prelude::GtkWindowExt;
+
 // XConnection utilities
 #[doc(hidden)]
 pub use crate::platform_impl::x11;
@@ -78,6 +80,25 @@ pub trait WindowExtUnix {
 
   /// Whether to show the window icon in the taskbar or not.
   fn set_skip_taskbar(&self, skip: bool) -> Result<(), ExternalError>;
+
+  /// Sets the startup notification id of the window, usually taken from the `DESKTOP_STARTUP_ID`
+  /// environment variable set by the launcher. This lets the shell stop its "launching" feedback
+  /// once the window is shown.
+  ///
+  /// On X11, if the window has already been shown, this sets its `_NET_STARTUP_ID` property and
+  /// completes the startup notification right away. Otherwise this is done by GTK when the
+  /// window is shown.
+  ///
+  /// GTK already uses `DESKTOP_STARTUP_ID` for the first window it shows, this is only needed
+  /// for windows shown later on, e.g. when an already running instance is activated again.
+  fn set_startup_id(&self, id: &str);
+
+  /// Sets the X11 `WM_CLASS` property of the window, used by window managers and taskbars to
+  /// match and group windows.
+  ///
+  /// This must be called before the window is shown for the first time, it is ignored afterwards.
+  /// On Wayland, use [`EventLoopBuilderExtUnix::with_app_id`] instead.
+  fn set_wm_class(&self, instance: &str, class: &str);
 }
 
 impl WindowExtUnix for Window {
@@ -93,6 +114,14 @@ impl WindowExtUnix for Window {
     self.window.set_skip_taskbar(skip)
   }
 
+  fn set_startup_id(&self, id: &str) {
+    self.window.set_startup_id(id)
+  }
+
+  fn set_wm_class(&self, instance: &str, class: &str) {
+    self.window.set_wm_class(instance, class)
+  }
+
   fn new_from_gtk_window<T: 'static>(
     event_loop_window_target: &EventLoopWindowTarget<T>,
     window: gtk::ApplicationWindow,
@@ -84,6 +84,8 @@ impl<T: 'static> EventLoop<T> {
         p: EventLoopWindowTarget {
           _marker: std::marker::PhantomData,
         },
+        event_filter: Default::default(),
+        idle_callback: Default::default(),
         _marker: std::marker::PhantomData,
       },
       sender_to_clone: sender,
@@ -162,6 +164,18 @@ impl<T: 'static> EventLoop<T> {
             //   call_event_handler!(event_handler, self.window_target(), control_flow, event);
             // }
           }
+          Event::WindowCreated => {
+            // Occlusion isn't tracked on Android, the window is reported as visible once.
+            call_event_handler!(
+              event_handler,
+              self.window_target(),
+              control_flow,
+              event::Event::WindowEvent {
+                window_id: window::WindowId(WindowId),
+                event: event::WindowEvent::OcclusionStateChanged(event::OcclusionState::Visible),
+              }
+            );
+          }
           Event::WindowHasFocus => {
             call_event_handler!(
               event_handler,
@@ -524,10 +538,32 @@ impl Window {
     // TODO
   }
 
+  pub fn set_force_above_fullscreen(&self, _force_above_fullscreen: bool) {
+    warn!("`Window::set_force_above_fullscreen` is ignored on Android")
+  }
+
+  pub fn force_redraw_now(&self) {
+    warn!("`Window::force_redraw_now` is ignored on Android")
+  }
+
   pub fn inner_position(&self) -> Result<PhysicalPosition<i32>, error::NotSupportedError> {
     Err(error::NotSupportedError::new())
   }
 
+  pub fn screen_to_window(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, error::ExternalError> {
+    Ok(position)
+  }
+
+  pub fn window_to_screen(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, error::ExternalError> {
+    Ok(position)
+  }
+
   pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, error::NotSupportedError> {
     Err(error::NotSupportedError::new())
   }
@@ -536,6 +572,10 @@ impl Window {
     // no effect
   }
 
+  pub fn set_outer_position_with_error(&self, _position: Position) -> Result<(), error::OsError> {
+    Err(os_error!(OsError))
+  }
+
   pub fn inner_size(&self) -> PhysicalSize<u32> {
     self.outer_size()
   }
@@ -550,6 +590,10 @@ impl Window {
 
   pub fn set_min_inner_size(&self, _: Option<Size>) {}
   pub fn set_max_inner_size(&self, _: Option<Size>) {}
+  pub fn set_resize_increments(&self, _increments: Option<Size>) {
+    warn!("`Window::set_resize_increments` is ignored on Android")
+  }
+
   pub fn set_inner_size_constraints(&self, _: WindowSizeConstraints) {}
 
   pub fn set_title(&self, _title: &str) {}
@@ -578,6 +622,14 @@ impl Window {
     warn!("`Window::set_resizable` is ignored on Android")
   }
 
+  pub fn set_resize_behavior(&self, _behavior: window::ResizeBehavior) {
+    warn!("`Window::set_resize_behavior` is ignored on Android")
+  }
+
+  pub fn set_resize_border_width(&self, _width: u32) {
+    warn!("`Window::set_resize_border_width` is ignored on Android")
+  }
+
   pub fn set_minimizable(&self, _minimizable: bool) {
     warn!("`Window::set_minimizable` is ignored on Android")
   }
@@ -654,6 +706,8 @@ impl Window {
 
   pub fn set_cursor_icon(&self, _: window::CursorIcon) {}
 
+  pub fn set_busy_cursor(&self, _: bool) {}
+
   pub fn set_cursor_position(&self, _: Position) -> Result<(), error::ExternalError> {
     Err(error::ExternalError::NotSupported(
       error::NotSupportedError::new(),
@@ -668,6 +722,16 @@ impl Window {
 
   pub fn set_cursor_visible(&self, _: bool) {}
 
+  pub fn set_cursor_grab_and_visibility(
+    &self,
+    _mode: window::CursorGrabMode,
+    _visible: bool,
+  ) -> Result<(), error::ExternalError> {
+    Err(error::ExternalError::NotSupported(
+      error::NotSupportedError::new(),
+    ))
+  }
+
   pub fn drag_window(&self) -> Result<(), error::ExternalError> {
     Err(error::ExternalError::NotSupported(
       error::NotSupportedError::new(),
@@ -828,6 +892,14 @@ impl MonitorHandle {
       .unwrap_or(1.0)
   }
 
+  pub fn physical_size_mm(&self) -> (u32, u32) {
+    (0, 0)
+  }
+
+  pub fn refresh_rate_millihertz(&self) -> Option<u32> {
+    None
+  }
+
   pub fn video_modes(&self) -> impl Iterator<Item = monitor::VideoMode> {
     let size = self.size().into();
     let mut v = Vec::new();
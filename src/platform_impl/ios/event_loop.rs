@@ -128,6 +128,8 @@ impl<T: 'static> EventLoop<T> {
           receiver,
           sender_to_clone,
         },
+        event_filter: Default::default(),
+        idle_callback: Default::default(),
         _marker: PhantomData,
       },
     }
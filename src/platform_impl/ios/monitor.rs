@@ -238,6 +238,22 @@ impl Inner {
     }
   }
 
+  pub fn physical_size_mm(&self) -> (u32, u32) {
+    (0, 0)
+  }
+
+  pub fn refresh_rate_millihertz(&self) -> Option<u32> {
+    let os_capabilities = app_state::os_capabilities();
+    if os_capabilities.maximum_frames_per_second {
+      let refresh_rate: NSInteger = unsafe { msg_send![self.ui_screen(), maximumFramesPerSecond] };
+      Some(refresh_rate as u32 * 1000)
+    } else {
+      // All devices without `maximumFramesPerSecond` have 60hz displays, see
+      // `VideoMode::retained_new`.
+      Some(60_000)
+    }
+  }
+
   pub fn video_modes(&self) -> impl Iterator<Item = RootVideoMode> {
     let mut modes = BTreeSet::new();
     unsafe {
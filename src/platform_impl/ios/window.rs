@@ -12,7 +12,7 @@ use objc::runtime::{Class, Object, BOOL, NO, YES};
 use crate::{
   dpi::{self, LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size},
   error::{ExternalError, NotSupportedError, OsError as RootOsError},
-  event::{Event, WindowEvent},
+  event::{Event, OcclusionState, WindowEvent},
   icon::Icon,
   monitor::MonitorHandle as RootMonitorHandle,
   platform::ios::{MonitorHandleExtIOS, ScreenEdge, ValidOrientations},
@@ -26,8 +26,8 @@ use crate::{
     monitor, view, EventLoopWindowTarget, MonitorHandle,
   },
   window::{
-    CursorIcon, Fullscreen, ResizeDirection, Theme, UserAttentionType, WindowAttributes,
-    WindowId as RootWindowId, WindowSizeConstraints,
+    CursorGrabMode, CursorIcon, Fullscreen, ResizeBehavior, ResizeDirection, Theme,
+    UserAttentionType, WindowAttributes, WindowId as RootWindowId, WindowSizeConstraints,
   },
 };
 
@@ -112,6 +112,28 @@ impl Inner {
     }
   }
 
+  pub fn screen_to_window(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let origin = self.inner_position().map_err(ExternalError::NotSupported)?;
+    Ok(PhysicalPosition::new(
+      position.x - origin.x,
+      position.y - origin.y,
+    ))
+  }
+
+  pub fn window_to_screen(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let origin = self.inner_position().map_err(ExternalError::NotSupported)?;
+    Ok(PhysicalPosition::new(
+      position.x + origin.x,
+      position.y + origin.y,
+    ))
+  }
+
   pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
     unsafe {
       let screen_frame = self.screen_frame();
@@ -141,6 +163,11 @@ impl Inner {
     }
   }
 
+  pub fn set_outer_position_with_error(&self, position: Position) -> Result<(), RootOsError> {
+    self.set_outer_position(position);
+    Ok(())
+  }
+
   pub fn inner_size(&self) -> PhysicalSize<u32> {
     unsafe {
       let scale_factor = self.scale_factor();
@@ -175,6 +202,10 @@ impl Inner {
   pub fn set_max_inner_size(&self, _: Option<Size>) {
     warn!("`Window::set_max_inner_size` is ignored on iOS")
   }
+  pub fn set_resize_increments(&self, _increments: Option<Size>) {
+    warn!("`Window::set_resize_increments` is ignored on iOS")
+  }
+
   pub fn set_inner_size_constraints(&self, _: WindowSizeConstraints) {
     warn!("`Window::set_inner_size_constraints` is ignored on iOS")
   }
@@ -183,6 +214,22 @@ impl Inner {
     warn!("`Window::set_resizable` is ignored on iOS")
   }
 
+  pub fn set_force_above_fullscreen(&self, _force_above_fullscreen: bool) {
+    warn!("`Window::set_force_above_fullscreen` is ignored on iOS")
+  }
+
+  pub fn force_redraw_now(&self) {
+    warn!("`Window::force_redraw_now` is ignored on iOS")
+  }
+
+  pub fn set_resize_behavior(&self, _behavior: ResizeBehavior) {
+    warn!("`Window::set_resize_behavior` is ignored on iOS")
+  }
+
+  pub fn set_resize_border_width(&self, _width: u32) {
+    warn!("`Window::set_resize_border_width` is ignored on iOS")
+  }
+
   pub fn set_minimizable(&self, _minimizable: bool) {
     warn!("`Window::set_minimizable` is ignored on iOS")
   }
@@ -206,6 +253,10 @@ impl Inner {
     debug!("`Window::set_cursor_icon` ignored on iOS")
   }
 
+  pub fn set_busy_cursor(&self, _busy: bool) {
+    debug!("`Window::set_busy_cursor` ignored on iOS")
+  }
+
   pub fn set_cursor_position(&self, _position: Position) -> Result<(), ExternalError> {
     Err(ExternalError::NotSupported(NotSupportedError::new()))
   }
@@ -218,6 +269,14 @@ impl Inner {
     debug!("`Window::set_cursor_visible` is ignored on iOS")
   }
 
+  pub fn set_cursor_grab_and_visibility(
+    &self,
+    _mode: CursorGrabMode,
+    _visible: bool,
+  ) -> Result<(), ExternalError> {
+    Err(ExternalError::NotSupported(NotSupportedError::new()))
+  }
+
   pub fn cursor_position(&self) -> Result<PhysicalPosition<f64>, ExternalError> {
     debug!("`Window::cursor_position` is ignored on iOS");
     Ok((0, 0).into())
@@ -572,6 +631,12 @@ impl Window {
         );
       }
 
+      // Occlusion isn't tracked on iOS, the window is reported as visible once.
+      app_state::handle_nonuser_event(EventWrapper::StaticEvent(Event::WindowEvent {
+        window_id: RootWindowId(window.into()),
+        event: WindowEvent::OcclusionStateChanged(OcclusionState::Visible),
+      }));
+
       Ok(result)
     }
   }
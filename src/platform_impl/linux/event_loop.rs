@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{
-  cell::RefCell,
+  cell::{Cell, RefCell},
   collections::{HashSet, VecDeque},
   error::Error,
   process,
@@ -25,10 +25,11 @@ use gtk::{
 };
 
 use crate::{
-  dpi::{LogicalPosition, LogicalSize, PhysicalPosition},
+  dpi::{LogicalPosition, LogicalSize, PhysicalPosition, Size},
   error::ExternalError,
   event::{
-    ElementState, Event, MouseButton, MouseScrollDelta, StartCause, TouchPhase, WindowEvent,
+    ElementState, Event, ImeEvent, MouseButton, MouseScrollDelta, OcclusionState, StartCause,
+    TouchPhase, WindowEvent,
   },
   event_loop::{ControlFlow, EventLoopClosed, EventLoopWindowTarget as RootELW},
   keyboard::ModifiersState,
@@ -43,7 +44,7 @@ use super::{
   keyboard,
   monitor::{self, MonitorHandle},
   taskbar, util,
-  window::{WindowId, WindowRequest},
+  window::{settings_theme, WindowId, WindowRequest},
 };
 
 use taskbar::TaskbarIndicator;
@@ -84,11 +85,7 @@ impl<T> EventLoopWindowTarget<T> {
 
   #[inline]
   pub fn primary_monitor(&self) -> Option<RootMonitorHandle> {
-    let monitor = self.display.primary_monitor();
-    monitor.and_then(|monitor| {
-      let handle = MonitorHandle { monitor };
-      Some(RootMonitorHandle { inner: handle })
-    })
+    monitor::primary_monitor(&self.display).map(|inner| RootMonitorHandle { inner })
   }
 
   #[cfg(feature = "rwh_05")]
@@ -160,6 +157,15 @@ impl<T> EventLoopWindowTarget<T> {
     }
   }
 
+  #[inline]
+  pub fn request_redraw_all(&self) {
+    for window_id in self.windows.borrow().iter() {
+      if let Err(e) = self.draw_tx.send(*window_id) {
+        log::warn!("Failed to send redraw event to event channel: {}", e);
+      }
+    }
+  }
+
   #[inline]
   pub fn set_theme(&self, theme: Option<Theme>) {
     if let Err(e) = self
@@ -263,6 +269,7 @@ impl<T: 'static> EventLoop<T> {
 
     let mut taskbar = TaskbarIndicator::new();
     let is_wayland = window_target.is_wayland();
+    let windows = window_target.windows.clone();
 
     // Window Request
     window_requests_rx.attach(Some(&context), move |(id, request)| {
@@ -274,6 +281,12 @@ impl<T: 'static> EventLoop<T> {
           WindowRequest::SizeConstraints(constraints) => {
             util::set_size_constraints(&window, constraints);
           }
+          WindowRequest::ResizeIncrements(increments) => match increments {
+            Some(increments) => unsafe { window.set_data("resize_increments", increments) },
+            None => unsafe {
+              window.steal_data::<Size>("resize_increments");
+            },
+          },
           WindowRequest::Visible(visible) => {
             if visible {
               window.show_all();
@@ -368,6 +381,9 @@ impl<T: 'static> EventLoop<T> {
             window.set_skip_taskbar_hint(skip);
             window.set_skip_pager_hint(skip)
           }
+          WindowRequest::ResizeBorderWidth(width) => {
+            unsafe { window.set_data("resize_border_width", width) };
+          }
           WindowRequest::BackgroundColor(css_provider, color) => {
             unsafe { window.set_data("background_color", color) };
 
@@ -398,16 +414,24 @@ impl<T: 'static> EventLoop<T> {
             }
           }
           WindowRequest::CursorIcon(cursor) => {
-            if let Some(gdk_window) = window.window() {
-              let display = window.display();
-              match cursor {
-                Some(cr) => {
-                  gdk_window.set_cursor(Cursor::from_name(&display, cr.to_str()).as_ref())
-                }
-                None => gdk_window
-                  .set_cursor(Cursor::for_display(&display, CursorType::BlankCursor).as_ref()),
+            let busy = unsafe { window.data::<bool>("busy_cursor").map(|b| *b.as_ref()) };
+            unsafe { window.set_data("cursor_icon", cursor) };
+            if busy != Some(true) {
+              set_cursor(&window, cursor);
+            }
+          }
+          WindowRequest::BusyCursor(busy) => {
+            unsafe { window.set_data("busy_cursor", busy) };
+            let cursor = if busy {
+              Some(CursorIcon::Wait)
+            } else {
+              unsafe {
+                window
+                  .data::<Option<CursorIcon>>("cursor_icon")
+                  .map_or(Some(CursorIcon::Default), |c| *c.as_ref())
               }
             };
+            set_cursor(&window, cursor);
           }
           WindowRequest::CursorPosition((x, y)) => {
             if let Some(cursor) = window
@@ -465,12 +489,12 @@ impl<T: 'static> EventLoop<T> {
             let fullscreen_ = fullscreen.clone();
             window.connect_motion_notify_event(move |window, event| {
               if !window.is_decorated() && window.is_resizable() && !window.is_maximized() {
+                let border = resize_border_width(window);
                 if let Some(window) = window.window() {
                   let (cx, cy) = event.root();
                   let (left, top) = window.position();
                   let (w, h) = (window.width(), window.height());
                   let (right, bottom) = (left + w, top + h);
-                  let border = window.scale_factor() * 5;
                   let edge = crate::window::hit_test(
                     (left, top, right, bottom),
                     cx as _,
@@ -499,7 +523,7 @@ impl<T: 'static> EventLoop<T> {
                 let (left, top) = window.position();
                 let (w, h) = window.size();
                 let (right, bottom) = (left + w, top + h);
-                let border = window.scale_factor() * 5;
+                let border = resize_border_width(window);
                 let edge = crate::window::hit_test(
                   (left, top, right, bottom),
                   cx as _,
@@ -525,13 +549,13 @@ impl<T: 'static> EventLoop<T> {
             });
             window.connect_touch_event(move |window, event| {
               if !window.is_decorated() && window.is_resizable() && !window.is_maximized() {
+                let border = resize_border_width(window);
                 if let Some(window) = window.window() {
                   if let Some((cx, cy)) = event.root_coords() {
                     if let Some(device) = event.device() {
                       let (left, top) = window.position();
                       let (w, h) = (window.width(), window.height());
                       let (right, bottom) = (left + w, top + h);
-                      let border = window.scale_factor() * 5;
                       let edge = crate::window::hit_test(
                         (left, top, right, bottom),
                         cx as _,
@@ -576,6 +600,7 @@ impl<T: 'static> EventLoop<T> {
             });
 
             let tx_clone = event_tx.clone();
+            let current_monitor = RefCell::new(None);
             window.connect_configure_event(move |window, event| {
               let scale_factor = window.scale_factor();
 
@@ -601,6 +626,28 @@ impl<T: 'static> EventLoop<T> {
                   e
                 );
               }
+
+              if let Some(monitor) = window
+                .window()
+                .and_then(|w| window.display().monitor_at_window(&w))
+              {
+                let previous_monitor = current_monitor.replace(Some(monitor.clone()));
+                if matches!(previous_monitor, Some(previous) if previous != monitor) {
+                  if let Err(e) = tx_clone.send(Event::WindowEvent {
+                    window_id: RootWindowId(id),
+                    event: WindowEvent::MonitorChanged {
+                      new_monitor: RootMonitorHandle {
+                        inner: MonitorHandle { monitor },
+                      },
+                    },
+                  }) {
+                    log::warn!(
+                      "Failed to send window monitor changed event to event channel: {}",
+                      e
+                    );
+                  }
+                }
+              }
               false
             });
 
@@ -645,6 +692,17 @@ impl<T: 'static> EventLoop<T> {
               }
             });
 
+            // Occlusion isn't tracked on Linux, the window is reported as visible once.
+            if let Err(e) = event_tx.send(Event::WindowEvent {
+              window_id: RootWindowId(id),
+              event: WindowEvent::OcclusionStateChanged(OcclusionState::Visible),
+            }) {
+              log::warn!(
+                "Failed to send occlusion state event to event channel: {}",
+                e
+              );
+            }
+
             let tx_clone = event_tx.clone();
             window.connect_enter_notify_event(move |_, _| {
               if let Err(e) = tx_clone.send(Event::WindowEvent {
@@ -816,7 +874,54 @@ impl<T: 'static> EventLoop<T> {
             let ime = gtk::IMContextSimple::default();
             ime.set_client_window(window.window().as_ref());
             ime.focus_in();
+            // Set while a composition session is active, or has just ended but
+            // its result hasn't been committed yet.
+            let in_preedit = Rc::new(Cell::new(false));
+            let send_ime_event = Rc::new(move |event: ImeEvent| {
+              if let Err(e) = tx_clone.send(Event::WindowEvent {
+                window_id: RootWindowId(id),
+                event: WindowEvent::Ime(event),
+              }) {
+                log::warn!("Failed to send IME event to event channel: {}", e);
+              }
+            });
+
+            let send_ime = send_ime_event.clone();
+            let in_preedit_ = in_preedit.clone();
+            ime.connect_preedit_start(move |_| {
+              in_preedit_.set(true);
+              send_ime(ImeEvent::Enabled);
+            });
+
+            let send_ime = send_ime_event.clone();
+            ime.connect_preedit_changed(move |ime| {
+              let (text, _, cursor) = ime.preedit_string();
+              let cursor = text
+                .char_indices()
+                .nth(cursor as usize)
+                .map(|(offset, _)| offset)
+                .unwrap_or(text.len());
+              send_ime(ImeEvent::Preedit(text.to_string(), Some((cursor, cursor))));
+            });
+
+            // GTK emits `preedit-end` right before committing the composed text, so `Disabled`
+            // is delayed to be sent after the `Commit` event.
+            let send_ime = send_ime_event.clone();
+            let in_preedit_ = in_preedit.clone();
+            ime.connect_preedit_end(move |_| {
+              let send_ime = send_ime.clone();
+              let in_preedit = in_preedit_.clone();
+              glib::idle_add_local_once(move || {
+                in_preedit.set(false);
+                send_ime(ImeEvent::Disabled);
+              });
+            });
+
+            let tx_clone = event_tx.clone();
             ime.connect_commit(move |_, s| {
+              if in_preedit.get() {
+                send_ime_event(ImeEvent::Commit(s.to_string()));
+              }
               if let Err(e) = tx_clone.send(Event::WindowEvent {
                 window_id: RootWindowId(id),
                 event: WindowEvent::ReceivedImeText(s.to_string()),
@@ -920,10 +1025,23 @@ impl<T: 'static> EventLoop<T> {
           }
           WindowRequest::SetTheme(theme) => {
             if let Some(settings) = Settings::default() {
+              let previous_theme = settings_theme(&settings);
               match theme {
                 Some(Theme::Dark) => settings.set_gtk_application_prefer_dark_theme(true),
                 Some(Theme::Light) | None => settings.set_gtk_application_prefer_dark_theme(false),
               }
+              let new_theme = settings_theme(&settings);
+              // The preference is app-wide, so every window follows the new theme.
+              if new_theme != previous_theme {
+                for window_id in windows.borrow().iter() {
+                  if let Err(e) = event_tx.send(Event::WindowEvent {
+                    window_id: RootWindowId(*window_id),
+                    event: WindowEvent::ThemeChanged(new_theme),
+                  }) {
+                    log::warn!("Failed to send theme changed event to event channel: {}", e);
+                  }
+                }
+              }
             }
           }
           _ => unreachable!(),
@@ -936,6 +1054,8 @@ impl<T: 'static> EventLoop<T> {
     let event_loop = Self {
       window_target: RootELW {
         p: window_target,
+        event_filter: Default::default(),
+        idle_callback: Default::default(),
         _marker: std::marker::PhantomData,
       },
       user_event_tx,
@@ -1162,6 +1282,34 @@ impl<T: 'static> EventLoopProxy<T> {
   }
 }
 
+/// Width of the resize border of undecorated windows, scaled like the hit-test coordinates.
+fn resize_border_width(window: &gtk::Window) -> i32 {
+  let width = unsafe {
+    window
+      .data::<u32>("resize_border_width")
+      .map(|w| *w.as_ref())
+  };
+  scale_resize_border_width(width, window.scale_factor())
+}
+
+/// Scales the configured border width, `5` if none was set, by the monitor's scale factor.
+fn scale_resize_border_width(width: Option<u32>, scale_factor: i32) -> i32 {
+  width.unwrap_or(5) as i32 * scale_factor
+}
+
+/// Sets the cursor of the window, `None` hides it.
+fn set_cursor(window: &gtk::Window, cursor: Option<CursorIcon>) {
+  if let Some(gdk_window) = window.window() {
+    let display = window.display();
+    match cursor {
+      Some(cr) => gdk_window.set_cursor(Cursor::from_name(&display, cr.to_str()).as_ref()),
+      None => {
+        gdk_window.set_cursor(Cursor::for_display(&display, CursorType::BlankCursor).as_ref())
+      }
+    }
+  };
+}
+
 fn assert_is_main_thread(suggested_method: &str) {
   assert!(
     is_main_thread(),
@@ -1259,3 +1407,16 @@ impl ResizeDirection {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::scale_resize_border_width;
+
+  #[test]
+  fn resize_border_width_is_scaled_per_monitor() {
+    assert_eq!(scale_resize_border_width(None, 1), 5);
+    assert_eq!(scale_resize_border_width(None, 2), 10);
+    assert_eq!(scale_resize_border_width(Some(10), 1), 10);
+    assert_eq!(scale_resize_border_width(Some(10), 2), 20);
+  }
+}
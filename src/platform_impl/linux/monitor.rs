@@ -50,6 +50,22 @@ impl MonitorHandle {
     self.monitor.scale_factor() as f64
   }
 
+  #[inline]
+  pub fn physical_size_mm(&self) -> (u32, u32) {
+    (
+      self.monitor.width_mm().max(0) as u32,
+      self.monitor.height_mm().max(0) as u32,
+    )
+  }
+
+  #[inline]
+  pub fn refresh_rate_millihertz(&self) -> Option<u32> {
+    match self.monitor.refresh_rate() {
+      0 => None,
+      rate => Some(rate as u32),
+    }
+  }
+
   #[inline]
   pub fn video_modes(&self) -> Box<dyn Iterator<Item = RootVideoMode>> {
     Box::new(Vec::new().into_iter())
@@ -84,6 +100,14 @@ impl VideoMode {
   }
 }
 
+/// Returns the monitor marked as primary, `None` when no monitor is attached or none is marked
+/// as primary.
+pub fn primary_monitor(display: &Display) -> Option<MonitorHandle> {
+  display
+    .primary_monitor()
+    .map(|monitor| MonitorHandle { monitor })
+}
+
 pub fn from_point(display: &Display, x: f64, y: f64) -> Option<MonitorHandle> {
   if let Some(monitor) = display.monitor_at_point(x as i32, y as i32) {
     (0..display.n_monitors())
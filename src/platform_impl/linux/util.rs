@@ -1,5 +1,5 @@
 use crate::{
-  dpi::{LogicalPosition, LogicalSize, PhysicalPosition},
+  dpi::{LogicalPosition, LogicalSize, PhysicalPosition, Size},
   error::ExternalError,
   window::WindowSizeConstraints,
 };
@@ -9,7 +9,7 @@ use gtk::{
     prelude::{DeviceExt, SeatExt},
     Display,
   },
-  glib::{self},
+  glib::{self, prelude::ObjectExt},
   traits::{GtkWindowExt, WidgetExt},
 };
 use std::{cell::RefCell, rc::Rc};
@@ -54,6 +54,19 @@ pub fn set_size_constraints<W: GtkWindowExt + WidgetExt>(
   let min_size: LogicalSize<i32> = constraints.min_size_logical(scale_factor);
   let max_size: LogicalSize<i32> = constraints.max_size_logical(scale_factor);
 
+  // Set by `Window::set_resize_increments`, the geometry hints replace each other so they are
+  // always applied together.
+  let increments = unsafe { window.data::<Size>("resize_increments") }
+    .map(|increments| unsafe { increments.as_ref() }.to_logical::<i32>(scale_factor))
+    .filter(|increments| increments.width > 0 && increments.height > 0);
+  let (width_inc, height_inc) = match increments {
+    Some(increments) => {
+      geom_mask |= gdk::WindowHints::RESIZE_INC;
+      (increments.width, increments.height)
+    }
+    None => (0, 0),
+  };
+
   let picky_none: Option<&gtk::Window> = None;
   window.set_geometry_hints(
     picky_none,
@@ -64,8 +77,8 @@ pub fn set_size_constraints<W: GtkWindowExt + WidgetExt>(
       max_size.height,
       0,
       0,
-      0,
-      0,
+      width_inc,
+      height_inc,
       0f64,
       0f64,
       gdk::Gravity::Center,
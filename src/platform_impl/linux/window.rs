@@ -26,8 +26,8 @@ use crate::{
   monitor::MonitorHandle as RootMonitorHandle,
   platform_impl::wayland::header::WlHeader,
   window::{
-    CursorIcon, Fullscreen, ProgressBarState, ResizeDirection, Theme, UserAttentionType,
-    WindowAttributes, WindowSizeConstraints, RGBA,
+    CursorGrabMode, CursorIcon, Fullscreen, ProgressBarState, ResizeBehavior, ResizeDirection,
+    Theme, UserAttentionType, WindowAttributes, WindowSizeConstraints, RGBA,
   },
 };
 
@@ -50,6 +50,22 @@ impl WindowId {
 // ref: https://github.com/WebKit/WebKit/blob/e44ffaa0d999a9807f76f1805943eea204cfdfbc/Source/WebKit/UIProcess/API/gtk/PageClientImpl.cpp#L587
 const GTK_THEME_SUFFIX_LIST: [&'static str; 3] = ["-dark", "-Dark", "-Darker"];
 
+/// Returns the app-wide theme resulting from the GTK settings.
+pub(crate) fn settings_theme(settings: &Settings) -> Theme {
+  if settings.is_gtk_application_prefer_dark_theme() {
+    return Theme::Dark;
+  }
+
+  if let Some(theme) = settings.gtk_theme_name() {
+    let theme = theme.as_str();
+    if GTK_THEME_SUFFIX_LIST.iter().any(|t| theme.ends_with(t)) {
+      return Theme::Dark;
+    }
+  }
+
+  Theme::Light
+}
+
 pub struct Window {
   /// Window id.
   pub(crate) window_id: WindowId,
@@ -123,11 +139,24 @@ impl Window {
 
     window.set_deletable(attributes.closable);
 
-    // Set Min/Max Size
+    // Set Min/Max Size and resize increments
+    if let Some(increments) = attributes.resize_increments {
+      unsafe { window.set_data("resize_increments", increments) };
+    }
     util::set_size_constraints(&window, attributes.inner_size_constraints);
 
     // Set Position
-    if let Some(position) = attributes.position {
+    if attributes.center {
+      // Same size as `outer_size`, including client-side decorations. Wayland ignores `move_`.
+      if let Some(monitor) = monitor::primary_monitor(&window.display()) {
+        let geometry = monitor.monitor.geometry();
+        let (width, height) = window.size();
+        window.move_(
+          geometry.x() + (geometry.width() - width) / 2,
+          geometry.y() + (geometry.height() - height) / 2,
+        );
+      }
+    } else if let Some(position) = attributes.position {
       let (x, y): (i32, i32) = position.to_logical::<i32>(win_scale_factor as f64).into();
       window.move_(x, y);
     }
@@ -430,6 +459,15 @@ impl Window {
     }
   }
 
+  pub fn force_redraw_now(&self) {
+    self.window.queue_draw();
+    if let Some(window) = self.window.window() {
+      unsafe {
+        gtk::gdk::ffi::gdk_window_process_updates(window.to_glib_none().0, glib::ffi::GFALSE);
+      }
+    }
+  }
+
   pub fn inner_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
     let (x, y) = &*self.position;
     Ok(
@@ -438,6 +476,39 @@ impl Window {
     )
   }
 
+  /// Returns the position of the client area's top-left corner on the desktop.
+  fn client_origin(&self) -> Result<PhysicalPosition<i32>, ExternalError> {
+    match self.window.window() {
+      Some(window) => {
+        let (_, x, y) = window.origin();
+        Ok(LogicalPosition::new(x, y).to_physical(self.scale_factor()))
+      }
+      None => self.inner_position().map_err(ExternalError::NotSupported),
+    }
+  }
+
+  pub fn screen_to_window(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let origin = self.client_origin()?;
+    Ok(PhysicalPosition::new(
+      position.x - origin.x,
+      position.y - origin.y,
+    ))
+  }
+
+  pub fn window_to_screen(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let origin = self.client_origin()?;
+    Ok(PhysicalPosition::new(
+      position.x + origin.x,
+      position.y + origin.y,
+    ))
+  }
+
   pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
     let (x, y) = &*self.position;
     Ok(
@@ -460,6 +531,20 @@ impl Window {
     }
   }
 
+  pub fn set_outer_position_with_error(&self, position: Position) -> Result<(), RootOsError> {
+    // Wayland compositors don't let clients position their windows, `gtk_window_move` is
+    // silently ignored there.
+    if self.is_wayland() {
+      return Err(os_error!(super::OsError));
+    }
+
+    let (x, y): (i32, i32) = position.to_logical::<i32>(self.scale_factor()).into();
+    self
+      .window_requests_tx
+      .send((self.window_id, WindowRequest::Position((x, y))))
+      .map_err(|_| os_error!(super::OsError))
+  }
+
   pub fn set_background_color(&self, color: Option<RGBA>) {
     if let Err(e) = self.window_requests_tx.send((
       self.window_id,
@@ -530,6 +615,17 @@ impl Window {
     self.set_size_constraints(constraints)
   }
 
+  pub fn set_resize_increments(&self, increments: Option<Size>) {
+    if let Err(e) = self
+      .window_requests_tx
+      .send((self.window_id, WindowRequest::ResizeIncrements(increments)))
+    {
+      log::warn!("Fail to send resize increments request: {}", e);
+    }
+    // The increments are applied together with the size constraints.
+    self.set_size_constraints(*self.inner_size_constraints.borrow())
+  }
+
   pub fn set_title(&self, title: &str) {
     if let Err(e) = self
       .window_requests_tx
@@ -580,6 +676,17 @@ impl Window {
     }
   }
 
+  pub fn set_resize_behavior(&self, _behavior: ResizeBehavior) {}
+
+  pub fn set_resize_border_width(&self, width: u32) {
+    if let Err(e) = self
+      .window_requests_tx
+      .send((self.window_id, WindowRequest::ResizeBorderWidth(width)))
+    {
+      log::warn!("Fail to send resize border width request: {}", e);
+    }
+  }
+
   pub fn set_minimizable(&self, _minimizable: bool) {}
 
   pub fn set_maximizable(&self, _maximizable: bool) {}
@@ -710,6 +817,8 @@ impl Window {
     }
   }
 
+  pub fn set_force_above_fullscreen(&self, _force_above_fullscreen: bool) {}
+
   pub fn set_window_icon(&self, window_icon: Option<Icon>) {
     if let Err(e) = self
       .window_requests_tx
@@ -749,6 +858,15 @@ impl Window {
     }
   }
 
+  pub fn set_busy_cursor(&self, busy: bool) {
+    if let Err(e) = self
+      .window_requests_tx
+      .send((self.window_id, WindowRequest::BusyCursor(busy)))
+    {
+      log::warn!("Fail to send busy cursor request: {}", e);
+    }
+  }
+
   pub fn set_cursor_position<P: Into<Position>>(&self, position: P) -> Result<(), ExternalError> {
     let inner_pos = self.inner_position().unwrap_or_default();
     let (x, y): (i32, i32) = position
@@ -795,6 +913,18 @@ impl Window {
     }
   }
 
+  pub fn set_cursor_grab_and_visibility(
+    &self,
+    mode: CursorGrabMode,
+    visible: bool,
+  ) -> Result<(), ExternalError> {
+    if mode != CursorGrabMode::None {
+      return Err(ExternalError::NotSupported(NotSupportedError::new()));
+    }
+    self.set_cursor_visible(visible);
+    Ok(())
+  }
+
   #[inline]
   pub fn cursor_position(&self) -> Result<PhysicalPosition<f64>, ExternalError> {
     util::cursor_position(self.is_wayland())
@@ -831,10 +961,7 @@ impl Window {
 
   pub fn primary_monitor(&self) -> Option<RootMonitorHandle> {
     let display = self.window.display();
-    display.primary_monitor().map(|monitor| {
-      let handle = MonitorHandle { monitor };
-      RootMonitorHandle { inner: handle }
-    })
+    monitor::primary_monitor(&display).map(|inner| RootMonitorHandle { inner })
   }
 
   #[inline]
@@ -970,6 +1097,63 @@ impl Window {
     Ok(())
   }
 
+  pub fn set_wm_class(&self, instance: &str, class: &str) {
+    if self.window.is_realized() {
+      log::warn!("`WindowExtUnix::set_wm_class` is ignored once the window has been shown");
+      return;
+    }
+
+    let window = self.window.upcast_ref::<gtk::Window>();
+    unsafe {
+      gtk::ffi::gtk_window_set_wmclass(
+        window.to_glib_none().0,
+        instance.to_glib_none().0,
+        class.to_glib_none().0,
+      );
+    }
+  }
+
+  pub fn set_startup_id(&self, id: &str) {
+    // Used by GTK for windows that are not realized yet and on Wayland.
+    self.window.set_startup_id(id);
+
+    if self.is_wayland() {
+      return;
+    }
+
+    if let Some(window) = self.window.window() {
+      unsafe {
+        let display = window.display();
+        let xdisplay = gdk_x11_sys::gdk_x11_display_get_xdisplay(display.as_ptr() as *mut _);
+        let xid = gdk_x11_sys::gdk_x11_window_get_xid(window.as_ptr() as *mut _);
+        let startup_id_atom = gdk_x11_sys::gdk_x11_get_xatom_by_name_for_display(
+          display.as_ptr() as *mut _,
+          b"_NET_STARTUP_ID\0".as_ptr() as *const _,
+        );
+        let utf8_string_atom = gdk_x11_sys::gdk_x11_get_xatom_by_name_for_display(
+          display.as_ptr() as *mut _,
+          b"UTF8_STRING\0".as_ptr() as *const _,
+        );
+        if let Ok(xlib) = x11_dl::xlib::Xlib::open() {
+          (xlib.XChangeProperty)(
+            xdisplay as _,
+            xid,
+            startup_id_atom,
+            utf8_string_atom,
+            8,
+            x11_dl::xlib::PropModeReplace,
+            id.as_ptr(),
+            id.len() as _,
+          );
+        }
+      }
+
+      // Broadcasts the same `remove` message as `sn_launchee_context_complete`, without
+      // depending on libstartup-notification.
+      gtk::gdk::notify_startup_complete_with_id(id);
+    }
+  }
+
   pub fn set_progress_bar(&self, progress: ProgressBarState) {
     if let Err(e) = self
       .window_requests_tx
@@ -984,14 +1168,9 @@ impl Window {
       return theme;
     }
 
-    if let Some(theme) = Settings::default().and_then(|s| s.gtk_theme_name()) {
-      let theme = theme.as_str();
-      if GTK_THEME_SUFFIX_LIST.iter().any(|t| theme.ends_with(t)) {
-        return Theme::Dark;
-      }
-    }
-
-    Theme::Light
+    Settings::default()
+      .map(|settings| settings_theme(&settings))
+      .unwrap_or(Theme::Light)
   }
 
   pub fn set_theme(&self, theme: Option<Theme>) {
@@ -1016,6 +1195,7 @@ pub enum WindowRequest {
   Position((i32, i32)),
   Size((i32, i32)),
   SizeConstraints(WindowSizeConstraints),
+  ResizeIncrements(Option<Size>),
   Visible(bool),
   Focus,
   Resizable(bool),
@@ -1032,6 +1212,7 @@ pub enum WindowRequest {
   UserAttention(Option<UserAttentionType>),
   SetSkipTaskbar(bool),
   CursorIcon(Option<CursorIcon>),
+  BusyCursor(bool),
   CursorPosition((i32, i32)),
   CursorIgnoreEvents(bool),
   WireUpEvents {
@@ -1043,6 +1224,7 @@ pub enum WindowRequest {
   ProgressBarState(ProgressBarState),
   SetTheme(Option<Theme>),
   BackgroundColor(CssProvider, Option<RGBA>),
+  ResizeBorderWidth(u32),
 }
 
 impl Drop for Window {
@@ -26,7 +26,7 @@ use objc::runtime::{Object, NO, YES};
 use crate::{
   dpi::LogicalSize,
   event::{Event, StartCause, WindowEvent},
-  event_loop::{ControlFlow, EventLoopWindowTarget as RootWindowTarget},
+  event_loop::{ControlFlow, ControlFlowPrecision, EventLoopWindowTarget as RootWindowTarget},
   platform::macos::ActivationPolicy,
   platform_impl::{
     get_aux_state_mut,
@@ -303,6 +303,10 @@ impl AppState {
     HANDLER.set_in_callback(false);
   }
 
+  pub fn set_control_flow_precision(precision: ControlFlowPrecision) {
+    HANDLER.waker().set_precision(precision);
+  }
+
   pub fn open_urls(urls: Vec<url::Url>) {
     HANDLER.handle_nonuser_event(EventWrapper::StaticEvent(Event::Opened { urls }));
   }
@@ -17,7 +17,7 @@ use std::{
 use cocoa::{
   appkit::{NSApp, NSEventModifierFlags, NSEventSubtype, NSEventType::NSApplicationDefined},
   base::{id, nil, YES},
-  foundation::{NSAutoreleasePool, NSInteger, NSPoint, NSTimeInterval},
+  foundation::{NSAutoreleasePool, NSInteger, NSPoint, NSTimeInterval, NSUInteger},
 };
 use crossbeam_channel::{self as channel, Receiver, Sender};
 use scopeguard::defer;
@@ -26,7 +26,9 @@ use crate::{
   dpi::PhysicalPosition,
   error::ExternalError,
   event::Event,
-  event_loop::{ControlFlow, EventLoopClosed, EventLoopWindowTarget as RootWindowTarget},
+  event_loop::{
+    ControlFlow, ControlFlowPrecision, EventLoopClosed, EventLoopWindowTarget as RootWindowTarget,
+  },
   monitor::MonitorHandle as RootMonitorHandle,
   platform_impl::{
     platform::{
@@ -36,10 +38,11 @@ use crate::{
       monitor::{self, MonitorHandle},
       observer::*,
       util::{self, IdRef},
+      window,
     },
     set_progress_indicator,
   },
-  window::{ProgressBarState, Theme},
+  window::{ProgressBarState, Theme, WindowId as RootWindowId},
 };
 
 use super::window::set_ns_theme;
@@ -98,8 +101,7 @@ impl<T: 'static> EventLoopWindowTarget<T> {
 
   #[inline]
   pub fn primary_monitor(&self) -> Option<RootMonitorHandle> {
-    let monitor = monitor::primary_monitor();
-    Some(RootMonitorHandle { inner: monitor })
+    monitor::primary_monitor().map(|inner| RootMonitorHandle { inner })
   }
 
   #[cfg(feature = "rwh_05")]
@@ -127,7 +129,27 @@ impl<T: 'static> EventLoopWindowTarget<T> {
 
   #[inline]
   pub fn set_theme(&self, theme: Option<Theme>) {
-    set_ns_theme(theme)
+    set_ns_theme(theme);
+    util::notify_appearance_change_async();
+  }
+
+  pub fn request_redraw_all(&self) {
+    unsafe {
+      let ns_windows: id = msg_send![NSApp(), windows];
+      let count: NSUInteger = msg_send![ns_windows, count];
+      for i in 0..count {
+        let ns_window: id = msg_send![ns_windows, objectAtIndex: i];
+        // Skip windows that weren't created by tao, like panels and alerts.
+        if window::is_tao_window(ns_window) {
+          AppState::queue_redraw(RootWindowId(window::get_window_id(ns_window)));
+        }
+      }
+    }
+  }
+
+  #[inline]
+  pub fn set_control_flow_precision(&self, precision: ControlFlowPrecision) {
+    AppState::set_control_flow_precision(precision)
   }
 }
 
@@ -176,6 +198,8 @@ impl<T> EventLoop<T> {
       delegate,
       window_target: Rc::new(RootWindowTarget {
         p: Default::default(),
+        event_filter: Default::default(),
+        idle_callback: Default::default(),
         _marker: PhantomData,
       }),
       panic_info,
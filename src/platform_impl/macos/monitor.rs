@@ -14,7 +14,7 @@ use crate::{
 };
 use cocoa::{
   appkit::{CGPoint, NSScreen},
-  base::{id, nil},
+  base::{id, nil, BOOL, NO},
   foundation::NSUInteger,
 };
 use core_foundation::{
@@ -157,8 +157,16 @@ pub fn available_monitors() -> VecDeque<MonitorHandle> {
   }
 }
 
-pub fn primary_monitor() -> MonitorHandle {
-  MonitorHandle(CGDisplay::main().id)
+pub fn primary_monitor() -> Option<MonitorHandle> {
+  valid_monitor(CGDisplay::main().id)
+}
+
+fn valid_monitor(id: CGDirectDisplayID) -> Option<MonitorHandle> {
+  // `CGMainDisplayID` returns `kCGNullDirectDisplay` when there is no display attached.
+  if id == 0 {
+    return None;
+  }
+  Some(MonitorHandle(id))
 }
 
 // `from_point` get a monitor handle which contains the given point.
@@ -205,9 +213,19 @@ impl MonitorHandle {
   }
 
   pub fn name(&self) -> Option<String> {
-    let MonitorHandle(display_id) = *self;
-    let screen_num = CGDisplay::new(display_id).model_number();
-    Some(format!("Monitor #{}", screen_num))
+    let screen = self.ns_screen()?;
+    unsafe {
+      // `localizedName` is only available on macOS 10.15+
+      let has_name: BOOL = msg_send![screen, respondsToSelector: sel!(localizedName)];
+      if has_name == NO {
+        return None;
+      }
+      let name: id = msg_send![screen, localizedName];
+      if name == nil {
+        return None;
+      }
+      Some(util::ns_string_to_rust(name))
+    }
   }
 
   #[inline]
@@ -240,21 +258,46 @@ impl MonitorHandle {
     unsafe { NSScreen::backingScaleFactor(screen) as f64 }
   }
 
-  pub fn video_modes(&self) -> impl Iterator<Item = RootVideoMode> {
-    let cv_refresh_rate = unsafe {
+  pub fn physical_size_mm(&self) -> (u32, u32) {
+    let size = CGDisplay::new(self.0).screen_size();
+    (size.width.round() as u32, size.height.round() as u32)
+  }
+
+  pub fn refresh_rate_millihertz(&self) -> Option<u32> {
+    let mode = CGDisplay::new(self.0).display_mode()?;
+    let refresh_rate = mode.refresh_rate();
+    if refresh_rate > 0.0 {
+      return Some((refresh_rate * 1000.0).round() as u32);
+    }
+
+    // CGDisplayModeGetRefreshRate returns 0.0 for any display that
+    // isn't a CRT
+    self.display_link_refresh_rate_millihertz()
+  }
+
+  /// The nominal refresh rate of the display according to CoreVideo, used when CoreGraphics
+  /// doesn't report one.
+  fn display_link_refresh_rate_millihertz(&self) -> Option<u32> {
+    unsafe {
       let mut display_link = std::ptr::null_mut();
-      assert_eq!(
-        ffi::CVDisplayLinkCreateWithCGDisplay(self.0, &mut display_link),
-        ffi::kCVReturnSuccess
-      );
+      if ffi::CVDisplayLinkCreateWithCGDisplay(self.0, &mut display_link) != ffi::kCVReturnSuccess {
+        return None;
+      }
       let time = ffi::CVDisplayLinkGetNominalOutputVideoRefreshPeriod(display_link);
       ffi::CVDisplayLinkRelease(display_link);
 
       // This value is indefinite if an invalid display link was specified
-      assert!(time.flags & ffi::kCVTimeIsIndefinite == 0);
+      if time.flags & ffi::kCVTimeIsIndefinite != 0 || time.time_value == 0 {
+        return None;
+      }
+      Some((time.time_scale as i64 * 1000 / time.time_value) as u32)
+    }
+  }
 
-      time.time_scale as i64 / time.time_value
-    };
+  pub fn video_modes(&self) -> impl Iterator<Item = RootVideoMode> {
+    // Headless and virtual displays may not have a valid display link, their modes then report
+    // `0` unless CoreGraphics knows the rate.
+    let cv_refresh_rate = self.display_link_refresh_rate_millihertz().unwrap_or(0) as i64 / 1000;
 
     let monitor = self.clone();
 
@@ -336,3 +379,18 @@ impl MonitorHandle {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::valid_monitor;
+
+  #[test]
+  fn no_monitor_is_none() {
+    assert!(valid_monitor(0).is_none());
+  }
+
+  #[test]
+  fn attached_monitor_is_some() {
+    assert_eq!(valid_monitor(1).map(|monitor| monitor.0), Some(1));
+  }
+}
@@ -11,10 +11,13 @@ use std::{
   time::Instant,
 };
 
-use crate::platform_impl::platform::{
-  app_state::AppState,
-  event_loop::{stop_app_on_panic, PanicInfo},
-  ffi,
+use crate::{
+  event_loop::ControlFlowPrecision,
+  platform_impl::platform::{
+    app_state::AppState,
+    event_loop::{stop_app_on_panic, PanicInfo},
+    ffi,
+  },
 };
 
 #[link(name = "CoreFoundation", kind = "framework")]
@@ -49,6 +52,7 @@ extern "C" {
   ) -> CFRunLoopTimerRef;
   pub fn CFRunLoopAddTimer(rl: CFRunLoopRef, timer: CFRunLoopTimerRef, mode: CFRunLoopMode);
   pub fn CFRunLoopTimerSetNextFireDate(timer: CFRunLoopTimerRef, fireDate: CFAbsoluteTime);
+  pub fn CFRunLoopTimerSetTolerance(timer: CFRunLoopTimerRef, tolerance: CFTimeInterval);
   pub fn CFRunLoopTimerInvalidate(time: CFRunLoopTimerRef);
 
   pub fn CFRunLoopSourceCreate(
@@ -247,6 +251,7 @@ pub fn setup_control_flow_observers(panic_info: Weak<PanicInfo>) {
 
 pub struct EventLoopWaker {
   timer: CFRunLoopTimerRef,
+  precision: ControlFlowPrecision,
 }
 
 impl Drop for EventLoopWaker {
@@ -275,7 +280,10 @@ impl Default for EventLoopWaker {
         ptr::null_mut(),
       );
       CFRunLoopAddTimer(CFRunLoopGetMain(), timer, kCFRunLoopCommonModes);
-      EventLoopWaker { timer }
+      EventLoopWaker {
+        timer,
+        precision: ControlFlowPrecision::default(),
+      }
     }
   }
 }
@@ -289,6 +297,10 @@ impl EventLoopWaker {
     unsafe { CFRunLoopTimerSetNextFireDate(self.timer, std::f64::MIN) }
   }
 
+  pub fn set_precision(&mut self, precision: ControlFlowPrecision) {
+    self.precision = precision;
+  }
+
   pub fn start_at(&mut self, instant: Instant) {
     let now = Instant::now();
     if now >= instant {
@@ -298,6 +310,12 @@ impl EventLoopWaker {
         let current = CFAbsoluteTimeGetCurrent();
         let duration = instant - now;
         let fsecs = duration.subsec_nanos() as f64 / 1_000_000_000.0 + duration.as_secs() as f64;
+        // Apple recommends a tolerance of at least 10% of the interval to save power.
+        let tolerance = match self.precision {
+          ControlFlowPrecision::HighResolution => 0.0,
+          ControlFlowPrecision::LowPower => fsecs * 0.1,
+        };
+        CFRunLoopTimerSetTolerance(self.timer, tolerance);
         CFRunLoopTimerSetNextFireDate(self.timer, current + fsecs)
       }
     }
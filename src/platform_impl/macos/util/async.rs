@@ -8,9 +8,9 @@ use std::{
 };
 
 use cocoa::{
-  appkit::{CGFloat, NSScreen, NSWindow, NSWindowStyleMask},
+  appkit::{CGFloat, NSApp, NSScreen, NSWindow, NSWindowStyleMask},
   base::{id, nil},
-  foundation::{NSPoint, NSSize, NSString},
+  foundation::{NSPoint, NSSize, NSString, NSUInteger},
 };
 use dispatch::Queue;
 use objc::{
@@ -20,7 +20,13 @@ use objc::{
 
 use crate::{
   dpi::LogicalSize,
-  platform_impl::platform::{ffi, util::IdRef, window::SharedState},
+  platform_impl::platform::{
+    app_state::AppState,
+    event::EventWrapper,
+    ffi,
+    util::IdRef,
+    window::{self, SharedState},
+  },
 };
 
 pub fn is_main_thread() -> bool {
@@ -51,6 +57,36 @@ fn run_on_main<R: Send>(f: impl FnOnce() -> R + Send) -> R {
   }
 }
 
+// `AppState::queue_event` panics when it isn't called from the main thread.
+pub fn queue_event_on_main(wrapper: EventWrapper) {
+  if is_main_thread() {
+    AppState::queue_event(wrapper);
+  } else {
+    let wrapper = MainThreadSafe(wrapper);
+    Queue::main().exec_async(move || {
+      let wrapper = wrapper;
+      AppState::queue_event(wrapper.0);
+    });
+  }
+}
+
+// The appearance is app-wide, but `AppleInterfaceThemeChangedNotification` only fires for
+// system-wide changes. This makes every window compare its theme with the app's appearance,
+// which emits `ThemeChanged` for the windows whose theme changed.
+pub fn notify_appearance_change_async() {
+  Queue::main().exec_async(|| unsafe {
+    let ns_windows: id = msg_send![NSApp(), windows];
+    let count: NSUInteger = msg_send![ns_windows, count];
+    for i in 0..count {
+      let ns_window: id = msg_send![ns_windows, objectAtIndex: i];
+      if window::is_tao_window(ns_window) {
+        let delegate: id = msg_send![ns_window, delegate];
+        let _: () = msg_send![delegate, effectiveAppearanceDidChangedOnMainThread: nil];
+      }
+    }
+  });
+}
+
 unsafe fn set_style_mask(ns_window: id, ns_view: id, mask: NSWindowStyleMask) {
   ns_window.setStyleMask_(mask);
   // If we don't do this, key handling will break
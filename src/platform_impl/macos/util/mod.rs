@@ -117,7 +117,8 @@ pub fn cursor_position() -> Result<PhysicalPosition<f64>, ExternalError> {
   let point: NSPoint = unsafe { msg_send![class!(NSEvent), mouseLocation] };
   let y = CGDisplay::main().pixels_high() as f64 - point.y;
   let point = LogicalPosition::new(point.x, y);
-  Ok(point.to_physical(super::monitor::primary_monitor().scale_factor()))
+  let scale_factor = super::monitor::primary_monitor().map_or(1.0, |m| m.scale_factor());
+  Ok(point.to_physical(scale_factor))
 }
 
 pub unsafe fn ns_string_id_ref(s: &str) -> IdRef {
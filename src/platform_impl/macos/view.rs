@@ -23,7 +23,8 @@ use objc::{
 use crate::{
   dpi::LogicalPosition,
   event::{
-    DeviceEvent, ElementState, Event, MouseButton, MouseScrollDelta, TouchPhase, WindowEvent,
+    DeviceEvent, ElementState, Event, ImeEvent, MouseButton, MouseScrollDelta, TouchPhase,
+    WindowEvent,
   },
   keyboard::{KeyCode, ModifiersState},
   platform_impl::platform::{
@@ -34,11 +35,12 @@ use crate::{
     window::get_window_id,
     DEVICE_ID,
   },
-  window::WindowId,
+  window::{CursorIcon, WindowId},
 };
 
 pub struct CursorState {
   pub visible: bool,
+  pub busy: bool,
   pub cursor: util::Cursor,
 }
 
@@ -46,6 +48,7 @@ impl Default for CursorState {
   fn default() -> Self {
     Self {
       visible: true,
+      busy: false,
       cursor: Default::default(),
     }
   }
@@ -416,10 +419,12 @@ extern "C" fn reset_cursor_rects(this: &Object, _sel: Sel) {
 
     let bounds: NSRect = msg_send![this, bounds];
     let cursor_state = state.cursor_state.lock().unwrap();
-    let cursor = if cursor_state.visible {
-      cursor_state.cursor.load()
-    } else {
+    let cursor = if !cursor_state.visible {
       util::invisible_cursor()
+    } else if cursor_state.busy {
+      util::Cursor::from(CursorIcon::Wait).load()
+    } else {
+      cursor_state.cursor.load()
     };
 
     if !cursor.is_null() {
@@ -467,27 +472,69 @@ extern "C" fn set_marked_text(
   this: &mut Object,
   _sel: Sel,
   string: id,
-  _selected_range: NSRange,
+  selected_range: NSRange,
   _replacement_range: NSRange,
 ) {
   trace!("Triggered `setMarkedText`");
   unsafe {
     let marked_text_ref = clear_marked_text(this);
     let has_attr: BOOL = msg_send![string, isKindOfClass: class!(NSAttributedString)];
-    if has_attr != NO {
+    let characters = if has_attr != NO {
       marked_text_ref.initWithAttributedString(string);
+      msg_send![string, string]
     } else {
       marked_text_ref.initWithString(string);
+      string
     };
 
     let state_ptr: *mut c_void = *this.get_ivar("taoState");
     let state = &mut *(state_ptr as *mut ViewState);
+    let window_id = WindowId(get_window_id(state.ns_window));
+
+    if !state.in_ime_preedit {
+      AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+        window_id,
+        event: WindowEvent::Ime(ImeEvent::Enabled),
+      }));
+    }
+
+    let text = util::ns_string_to_rust(characters);
+    let cursor = utf16_range_to_byte_range(&text, selected_range);
+    AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+      window_id,
+      event: WindowEvent::Ime(ImeEvent::Preedit(text, cursor)),
+    }));
+
     state.in_ime_preedit = true;
     state.key_triggered_ime = true;
   }
   trace!("Completed `setMarkedText`");
 }
 
+/// Converts a range of UTF-16 code units, as used by `NSString`, to a byte range of `text`.
+fn utf16_range_to_byte_range(text: &str, range: NSRange) -> Option<(usize, usize)> {
+  if range.location == NSNotFound as NSUInteger {
+    return None;
+  }
+
+  let to_byte_offset = |utf16_offset: usize| {
+    let mut utf16_count = 0;
+    for (byte_offset, c) in text.char_indices() {
+      if utf16_count >= utf16_offset {
+        return byte_offset;
+      }
+      utf16_count += c.len_utf16();
+    }
+    text.len()
+  };
+
+  let start = range.location as usize;
+  Some((
+    to_byte_offset(start),
+    to_byte_offset(start + range.length as usize),
+  ))
+}
+
 extern "C" fn unmark_text(this: &mut Object, _sel: Sel) {
   trace!("Triggered `unmarkText`");
   unsafe {
@@ -568,8 +615,24 @@ extern "C" fn insert_text(this: &Object, _sel: Sel, string: id, _replacement_ran
     // We don't need this now, but it's here if that changes.
     //let event: id = msg_send![NSApp(), currentEvent];
 
+    let window_id = WindowId(get_window_id(state.ns_window));
+    if state.in_ime_preedit {
+      AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+        window_id,
+        event: WindowEvent::Ime(ImeEvent::Preedit(String::new(), None)),
+      }));
+      AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+        window_id,
+        event: WindowEvent::Ime(ImeEvent::Commit(string.clone())),
+      }));
+      AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+        window_id,
+        event: WindowEvent::Ime(ImeEvent::Disabled),
+      }));
+    }
+
     AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
-      window_id: WindowId(get_window_id(state.ns_window)),
+      window_id,
       event: WindowEvent::ReceivedImeText(string),
     }));
     if state.in_ime_preedit {
@@ -736,6 +799,14 @@ extern "C" fn key_down(this: &mut Object, _sel: Sel, event: id) {
         // In this case we should cancel the IME session.
         let () = msg_send![this, unmarkText];
         state.in_ime_preedit = false;
+        AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+          window_id,
+          event: WindowEvent::Ime(ImeEvent::Preedit(String::new(), None)),
+        }));
+        AppState::queue_event(EventWrapper::StaticEvent(Event::WindowEvent {
+          window_id,
+          event: WindowEvent::Ime(ImeEvent::Disabled),
+        }));
       }
     }
     let window_event = Event::WindowEvent {
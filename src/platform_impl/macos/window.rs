@@ -5,7 +5,7 @@
 use std::{
   collections::VecDeque,
   convert::TryInto,
-  f64,
+  f64, mem,
   os::raw::c_void,
   sync::{
     atomic::{AtomicBool, Ordering},
@@ -18,12 +18,14 @@ use crate::{
     LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize, Position, Size, Size::Logical,
   },
   error::{ExternalError, NotSupportedError, OsError as RootOsError},
+  event::{Event, WindowEvent},
   icon::Icon,
   monitor::{MonitorHandle as RootMonitorHandle, VideoMode as RootVideoMode},
-  platform::macos::WindowExtMacOS,
+  platform::macos::{TabbingMode, VibrancyMaterial, WindowExtMacOS},
   platform_impl::{
     platform::{
       app_state::AppState,
+      event::EventWrapper,
       ffi,
       monitor::{self, MonitorHandle, VideoMode},
       util::{self, IdRef},
@@ -34,16 +36,16 @@ use crate::{
     set_progress_indicator,
   },
   window::{
-    CursorIcon, Fullscreen, ProgressBarState, ResizeDirection, Theme, UserAttentionType,
-    WindowAttributes, WindowId as RootWindowId, WindowSizeConstraints,
+    CursorGrabMode, CursorIcon, Fullscreen, ProgressBarState, ResizeBehavior, ResizeDirection,
+    Theme, UserAttentionType, WindowAttributes, WindowId as RootWindowId, WindowSizeConstraints,
   },
 };
 use cocoa::{
   appkit::{
     self, CGFloat, NSApp, NSApplication, NSApplicationPresentationOptions, NSColor, NSEvent,
     NSEventModifierFlags, NSEventSubtype, NSEventType, NSRequestUserAttentionType, NSScreen,
-    NSView, NSWindow, NSWindowButton, NSWindowCollectionBehavior, NSWindowOrderingMode,
-    NSWindowStyleMask,
+    NSView, NSVisualEffectBlendingMode, NSVisualEffectState, NSWindow, NSWindowButton,
+    NSWindowCollectionBehavior, NSWindowOrderingMode, NSWindowStyleMask, NSWindowTabbingMode,
   },
   base::{id, nil},
   foundation::{
@@ -59,6 +61,11 @@ use objc::{
 
 use super::{util::ns_string_to_rust, view::ViewState};
 
+/// Lets an always on top window show up in the spaces of fullscreen apps.
+const FORCE_ABOVE_FULLSCREEN_COLLECTION_BEHAVIOR: NSWindowCollectionBehavior =
+  NSWindowCollectionBehavior::NSWindowCollectionBehaviorCanJoinAllSpaces
+    .union(NSWindowCollectionBehavior::NSWindowCollectionBehaviorFullScreenAuxiliary);
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Id(pub usize);
 
@@ -74,6 +81,12 @@ pub fn get_window_id(window_cocoa_id: id) -> Id {
   Id(window_cocoa_id as *const Object as _)
 }
 
+/// Returns whether `ns_window` was created by tao.
+pub(crate) fn is_tao_window(ns_window: id) -> bool {
+  let is_tao_window: BOOL = unsafe { msg_send![ns_window, isKindOfClass: WINDOW_CLASS.0] };
+  is_tao_window == YES
+}
+
 #[non_exhaustive]
 #[derive(Clone)]
 pub enum Parent {
@@ -96,6 +109,8 @@ pub struct PlatformSpecificWindowBuilderAttributes {
   pub traffic_light_inset: Option<Position>,
   pub automatic_tabbing: bool,
   pub tabbing_identifier: Option<String>,
+  pub tabbing_mode: Option<TabbingMode>,
+  pub visual_effect: Option<VibrancyMaterial>,
 }
 
 impl Default for PlatformSpecificWindowBuilderAttributes {
@@ -115,6 +130,8 @@ impl Default for PlatformSpecificWindowBuilderAttributes {
       traffic_light_inset: None,
       automatic_tabbing: true,
       tabbing_identifier: None,
+      tabbing_mode: None,
+      visual_effect: None,
     }
   }
 }
@@ -265,6 +282,12 @@ fn create_window(
         ];
       }
 
+      if attrs.force_above_fullscreen {
+        let collection_behavior =
+          ns_window.collectionBehavior() | FORCE_ABOVE_FULLSCREEN_COLLECTION_BEHAVIOR;
+        ns_window.setCollectionBehavior_(collection_behavior);
+      }
+
       if attrs.always_on_bottom {
         let _: () = msg_send![
           *ns_window,
@@ -281,7 +304,11 @@ fn create_window(
         let _: () = msg_send![button, setEnabled: NO];
       }
 
-      if let Some(increments) = pl_attrs.resize_increments {
+      let resize_increments = attrs
+        .resize_increments
+        .map(|increments| increments.to_logical(NSWindow::backingScaleFactor(*ns_window) as f64))
+        .or(pl_attrs.resize_increments);
+      if let Some(increments) = resize_increments {
         let (x, y) = (increments.width, increments.height);
         if x >= 1.0 && y >= 1.0 {
           let size = NSSize::new(x as CGFloat, y as CGFloat);
@@ -295,16 +322,31 @@ fn create_window(
 
       if !pl_attrs.automatic_tabbing {
         NSWindow::setAllowsAutomaticWindowTabbing_(*ns_window, NO);
+        ns_window.setTabbingMode_(NSWindowTabbingMode::NSWindowTabbingModeDisallowed);
       }
 
       if let Some(tabbing_identifier) = &pl_attrs.tabbing_identifier {
         let _: () = msg_send![*ns_window, setTabbingIdentifier: NSString::alloc(nil).init_str(tabbing_identifier)];
       }
 
+      if let Some(tabbing_mode) = pl_attrs.tabbing_mode {
+        ns_window.setTabbingMode_(tabbing_mode.into());
+      }
+
       if !pl_attrs.has_shadow {
         ns_window.setHasShadow_(NO);
       }
-      if attrs.position.is_none() {
+      if attrs.center {
+        // `center` places windows slightly above the middle, center the whole frame instead.
+        if let Some(screen) = monitor::primary_monitor().and_then(|m| m.ns_screen()) {
+          let screen_frame = NSScreen::frame(screen);
+          let frame = NSWindow::frame(*ns_window);
+          ns_window.setFrameOrigin_(NSPoint::new(
+            screen_frame.origin.x + (screen_frame.size.width - frame.size.width) / 2.0,
+            screen_frame.origin.y + (screen_frame.size.height - frame.size.height) / 2.0,
+          ));
+        }
+      } else if attrs.position.is_none() {
         ns_window.center();
       }
 
@@ -359,6 +401,45 @@ pub(super) fn set_ns_theme(theme: Option<Theme>) {
   }
 }
 
+const VISUAL_EFFECT_VIEW_IDENTIFIER: &str = "TaoVisualEffectView";
+
+/// Replaces the visual effect view at the back of `ns_view`, if any.
+unsafe fn set_visual_effect(ns_view: id, material: Option<VibrancyMaterial>) {
+  // Imported here as it shares method names with `NSView`.
+  use appkit::NSVisualEffectView;
+
+  let subviews: id = msg_send![ns_view, subviews];
+  for i in 0..subviews.count() {
+    let subview = subviews.objectAtIndex(i);
+    let identifier: id = msg_send![subview, identifier];
+    if identifier != nil && identifier.isEqualToString(VISUAL_EFFECT_VIEW_IDENTIFIER) {
+      NSVisualEffectView::removeFromSuperview(subview);
+      break;
+    }
+  }
+
+  if let Some(material) = material {
+    let effect_view =
+      NSVisualEffectView::initWithFrame_(NSVisualEffectView::alloc(nil), NSView::bounds(ns_view));
+    effect_view.setMaterial_(material.into());
+    effect_view.setBlendingMode_(NSVisualEffectBlendingMode::BehindWindow);
+    effect_view.setState_(NSVisualEffectState::FollowsWindowActiveState);
+    let identifier = util::ns_string_id_ref(VISUAL_EFFECT_VIEW_IDENTIFIER);
+    let _: () = msg_send![effect_view, setIdentifier: *identifier];
+    let _: () = msg_send![
+      effect_view,
+      setAutoresizingMask: appkit::NSViewWidthSizable | appkit::NSViewHeightSizable
+    ];
+    let _: () = msg_send![
+      ns_view,
+      addSubview: effect_view
+      positioned: NSWindowOrderingMode::NSWindowBelow
+      relativeTo: nil
+    ];
+    let _: () = msg_send![effect_view, release];
+  }
+}
+
 struct WindowClass(*const Class);
 unsafe impl Send for WindowClass {}
 unsafe impl Sync for WindowClass {}
@@ -425,6 +506,8 @@ pub struct SharedState {
   save_presentation_opts: Option<NSApplicationPresentationOptions>,
   pub saved_desktop_display_mode: Option<(CGDisplay, CGDisplayMode)>,
   pub current_theme: Theme,
+  /// Used by `windowWillResize:toSize:`.
+  pub resize_behavior: ResizeBehavior,
 }
 
 impl SharedState {
@@ -492,6 +575,9 @@ impl UnownedWindow {
     unsafe {
       ns_window.setContentView_(*ns_view);
       ns_window.setInitialFirstResponder_(*ns_view);
+      if pl_attribs.visual_effect.is_some() {
+        set_visual_effect(*ns_view, pl_attribs.visual_effect);
+      }
     }
 
     let input_context = unsafe { util::create_input_context(*ns_view) };
@@ -663,6 +749,13 @@ impl UnownedWindow {
     AppState::queue_redraw(RootWindowId(self.id()));
   }
 
+  pub fn force_redraw_now(&self) {
+    unsafe {
+      let () = msg_send![*self.ns_view, setNeedsDisplay: YES];
+      let () = msg_send![*self.ns_view, displayIfNeeded];
+    }
+  }
+
   pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
     let frame_rect = unsafe { NSWindow::frame(*self.ns_window) };
     let position = LogicalPosition::new(
@@ -685,6 +778,50 @@ impl UnownedWindow {
     Ok(position.to_physical(scale_factor))
   }
 
+  pub fn screen_to_window(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let scale_factor = self.scale_factor();
+    let screen_point = util::window_position(position.to_logical(scale_factor));
+    let (view_point, view_height) = unsafe {
+      let window_rect = self
+        .ns_window
+        .convertRectFromScreen_(NSRect::new(screen_point, NSSize::new(0., 0.)));
+      (
+        self.ns_view.convertPoint_fromView_(window_rect.origin, nil),
+        NSView::frame(*self.ns_view).size.height,
+      )
+    };
+    // The view isn't flipped, its origin is the bottom-left corner.
+    let position = LogicalPosition::new(view_point.x, view_height - view_point.y);
+    Ok(position.to_physical(scale_factor))
+  }
+
+  pub fn window_to_screen(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let scale_factor = self.scale_factor();
+    let position: LogicalPosition<f64> = position.to_logical(scale_factor);
+    let screen_point = unsafe {
+      let view_point = NSPoint::new(
+        position.x,
+        NSView::frame(*self.ns_view).size.height - position.y,
+      );
+      let window_point: NSPoint = msg_send![*self.ns_view, convertPoint: view_point toView: nil];
+      self
+        .ns_window
+        .convertRectToScreen_(NSRect::new(window_point, NSSize::new(0., 0.)))
+        .origin
+    };
+    let position = LogicalPosition::new(
+      screen_point.x,
+      CGDisplay::main().pixels_high() as f64 - screen_point.y,
+    );
+    Ok(position.to_physical(scale_factor))
+  }
+
   pub fn set_outer_position(&self, position: Position) {
     let scale_factor = self.scale_factor();
     let position = position.to_logical(scale_factor);
@@ -693,6 +830,11 @@ impl UnownedWindow {
     }
   }
 
+  pub fn set_outer_position_with_error(&self, position: Position) -> Result<(), RootOsError> {
+    self.set_outer_position(position);
+    Ok(())
+  }
+
   #[inline]
   pub fn inner_size(&self) -> PhysicalSize<u32> {
     let view_frame = unsafe { NSView::frame(*self.ns_view) };
@@ -741,6 +883,17 @@ impl UnownedWindow {
     }
   }
 
+  pub fn set_resize_increments(&self, increments: Option<Size>) {
+    let increments = increments
+      .map(|increments| increments.to_logical::<f64>(self.scale_factor()))
+      .filter(|increments| increments.width >= 1.0 && increments.height >= 1.0)
+      .unwrap_or(LogicalSize::new(1.0, 1.0));
+    unsafe {
+      let size = NSSize::new(increments.width as CGFloat, increments.height as CGFloat);
+      self.ns_window.setResizeIncrements_(size);
+    }
+  }
+
   pub fn set_inner_size_constraints(&self, constraints: WindowSizeConstraints) {
     let scale_factor = self.scale_factor();
     unsafe {
@@ -771,6 +924,17 @@ impl UnownedWindow {
     } // Otherwise, we don't change the mask until we exit fullscreen.
   }
 
+  #[inline]
+  pub fn set_resize_behavior(&self, behavior: ResizeBehavior) {
+    self.shared_state.lock().unwrap().resize_behavior = behavior;
+  }
+
+  #[inline]
+  pub fn set_resize_border_width(&self, _width: u32) {
+    // Undecorated windows are resized through AppKit's own resize area, tao doesn't hit-test
+    // them on macOS.
+  }
+
   #[inline]
   pub fn set_minimizable(&self, minimizable: bool) {
     let mut mask = unsafe { self.ns_window.styleMask() };
@@ -815,6 +979,18 @@ impl UnownedWindow {
     }
   }
 
+  #[inline]
+  pub fn set_busy_cursor(&self, busy: bool) {
+    if let Some(cursor_access) = self.cursor_state.upgrade() {
+      cursor_access.lock().unwrap().busy = busy;
+    }
+    unsafe {
+      let _: () = msg_send![*self.ns_window,
+          invalidateCursorRectsForView:*self.ns_view
+      ];
+    }
+  }
+
   #[inline]
   pub fn set_cursor_grab(&self, grab: bool) -> Result<(), ExternalError> {
     // TODO: Do this for real https://stackoverflow.com/a/40922095/5435443
@@ -838,6 +1014,30 @@ impl UnownedWindow {
     }
   }
 
+  #[inline]
+  pub fn set_cursor_grab_and_visibility(
+    &self,
+    mode: CursorGrabMode,
+    visible: bool,
+  ) -> Result<(), ExternalError> {
+    let grab = match mode {
+      CursorGrabMode::None => false,
+      CursorGrabMode::Locked => true,
+      CursorGrabMode::Confined => {
+        return Err(ExternalError::NotSupported(NotSupportedError::new()))
+      }
+    };
+    self.set_cursor_visible(visible);
+    if grab && !visible {
+      // `set_cursor_visible` only takes effect once AppKit resets the cursor rects, switch to
+      // the invisible cursor right away so it isn't shown while locked.
+      unsafe {
+        let _: () = msg_send![util::invisible_cursor(), set];
+      }
+    }
+    self.set_cursor_grab(grab)
+  }
+
   #[inline]
   pub fn cursor_position(&self) -> Result<PhysicalPosition<f64>, ExternalError> {
     util::cursor_position()
@@ -1417,8 +1617,7 @@ impl UnownedWindow {
 
   #[inline]
   pub fn primary_monitor(&self) -> Option<RootMonitorHandle> {
-    let monitor = monitor::primary_monitor();
-    Some(RootMonitorHandle { inner: monitor })
+    monitor::primary_monitor().map(|inner| RootMonitorHandle { inner })
   }
 
   #[cfg(feature = "rwh_04")]
@@ -1471,8 +1670,18 @@ impl UnownedWindow {
 
   pub fn set_theme(&self, theme: Option<Theme>) {
     set_ns_theme(theme);
-    let mut state = self.shared_state.lock().unwrap();
-    state.current_theme = theme.unwrap_or_else(get_ns_theme);
+    let new_theme = theme.unwrap_or_else(get_ns_theme);
+    let previous_theme = {
+      let mut state = self.shared_state.lock().unwrap();
+      mem::replace(&mut state.current_theme, new_theme)
+    };
+    if previous_theme != new_theme {
+      util::queue_event_on_main(EventWrapper::StaticEvent(Event::WindowEvent {
+        window_id: RootWindowId(self.id()),
+        event: WindowEvent::ThemeChanged(new_theme),
+      }));
+    }
+    util::notify_appearance_change_async();
   }
 
   pub fn set_content_protection(&self, enabled: bool) {
@@ -1481,6 +1690,18 @@ impl UnownedWindow {
     }
   }
 
+  pub fn set_force_above_fullscreen(&self, force_above_fullscreen: bool) {
+    unsafe {
+      let mut collection_behavior = self.ns_window.collectionBehavior();
+      if force_above_fullscreen {
+        collection_behavior |= FORCE_ABOVE_FULLSCREEN_COLLECTION_BEHAVIOR;
+      } else {
+        collection_behavior &= !FORCE_ABOVE_FULLSCREEN_COLLECTION_BEHAVIOR;
+      }
+      self.ns_window.setCollectionBehavior_(collection_behavior)
+    }
+  }
+
   pub fn set_visible_on_all_workspaces(&self, visible: bool) {
     unsafe {
       let mut collection_behavior = self.ns_window.collectionBehavior();
@@ -1600,6 +1821,26 @@ impl WindowExtMacOS for UnownedWindow {
     }
   }
 
+  #[inline]
+  fn toggle_fullscreen_animated(&self) {
+    let not_fullscreen = match self.shared_state.lock().unwrap().fullscreen {
+      Some(Fullscreen::Exclusive(_)) => return,
+      Some(Fullscreen::Borderless(_)) => false,
+      None => true,
+    };
+
+    // The fullscreen state is updated by `window_will_enter_fullscreen` and
+    // `window_did_exit_fullscreen`, as with the fullscreen button.
+    unsafe {
+      util::toggle_full_screen_async(
+        *self.ns_window,
+        *self.ns_view,
+        not_fullscreen,
+        Arc::downgrade(&self.shared_state),
+      );
+    }
+  }
+
   #[inline]
   fn has_shadow(&self) -> bool {
     unsafe { self.ns_window.hasShadow() == YES }
@@ -1643,8 +1884,14 @@ impl WindowExtMacOS for UnownedWindow {
 
   #[inline]
   fn set_allows_automatic_window_tabbing(&self, enabled: bool) {
+    let tabbing_mode = if enabled {
+      NSWindowTabbingMode::NSWindowTabbingModeAutomatic
+    } else {
+      NSWindowTabbingMode::NSWindowTabbingModeDisallowed
+    };
     unsafe {
-      NSWindow::setAllowsAutomaticWindowTabbing_(*self.ns_window, if enabled { YES } else { NO })
+      NSWindow::setAllowsAutomaticWindowTabbing_(*self.ns_window, if enabled { YES } else { NO });
+      self.ns_window.setTabbingMode_(tabbing_mode);
     }
   }
 
@@ -1672,6 +1919,13 @@ impl WindowExtMacOS for UnownedWindow {
     }
   }
 
+  #[inline]
+  fn merge_all_windows(&self) {
+    unsafe {
+      let _: () = msg_send![*self.ns_window, mergeAllWindows: nil];
+    }
+  }
+
   #[inline]
   fn set_fullsize_content_view(&self, fullsize: bool) {
     let mut mask = unsafe { self.ns_window.styleMask() };
@@ -1691,6 +1945,43 @@ impl WindowExtMacOS for UnownedWindow {
         .setTitlebarAppearsTransparent_(transparent as BOOL);
     }
   }
+
+  #[inline]
+  fn set_excluded_from_windows_menu(&self, excluded: bool) {
+    unsafe {
+      let _: () = msg_send![*self.ns_window, setExcludedFromWindowsMenu: excluded as BOOL];
+    }
+  }
+
+  #[inline]
+  fn is_excluded_from_windows_menu(&self) -> bool {
+    unsafe {
+      let is_excluded: BOOL = msg_send![*self.ns_window, isExcludedFromWindowsMenu];
+      is_excluded == YES
+    }
+  }
+
+  #[inline]
+  fn set_visual_effect(&self, material: Option<VibrancyMaterial>) {
+    unsafe { set_visual_effect(*self.ns_view, material) };
+  }
+
+  #[inline]
+  fn set_frame_autosave_name(&self, name: &str) {
+    unsafe {
+      let name = util::ns_string_id_ref(name);
+      let _: BOOL = msg_send![*self.ns_window, setFrameAutosaveName: *name];
+    }
+  }
+
+  #[inline]
+  fn restore_frame_using_name(&self, name: &str) -> bool {
+    unsafe {
+      let name = util::ns_string_id_ref(name);
+      let restored: BOOL = msg_send![*self.ns_window, setFrameUsingName: *name];
+      restored == YES
+    }
+  }
 }
 
 impl Drop for UnownedWindow {
@@ -9,9 +9,9 @@ use std::{
 };
 
 use cocoa::{
-  appkit::{self, NSApplicationPresentationOptions, NSView, NSWindow},
+  appkit::{self, NSApplicationPresentationOptions, NSView, NSWindow, NSWindowOcclusionState},
   base::{id, nil},
-  foundation::{NSAutoreleasePool, NSString, NSUInteger},
+  foundation::{NSAutoreleasePool, NSPoint, NSSize, NSString, NSUInteger},
 };
 use objc::{
   declare::ClassDecl,
@@ -20,7 +20,7 @@ use objc::{
 
 use crate::{
   dpi::{LogicalPosition, LogicalSize},
-  event::{Event, WindowEvent},
+  event::{Event, OcclusionState, WindowEvent},
   keyboard::ModifiersState,
   platform_impl::platform::{
     app_state::AppState,
@@ -29,7 +29,7 @@ use crate::{
     view::ViewState,
     window::{get_ns_theme, get_window_id, UnownedWindow},
   },
-  window::{Fullscreen, WindowId},
+  window::{Fullscreen, ResizeBehavior, WindowId},
 };
 
 pub struct WindowDelegateState {
@@ -50,6 +50,10 @@ pub struct WindowDelegateState {
   // During `windowDidResize`, we use this to only send Moved if the position changed.
   previous_position: Option<(f64, f64)>,
 
+  // Set by `windowWillResize:toSize:` when the window has a `ResizeBehavior` other than
+  // the default, `windowDidResize` then moves the frame to this origin.
+  resize_origin: Option<NSPoint>,
+
   // Used to prevent redundant events.
   previous_scale_factor: f64,
 
@@ -67,6 +71,7 @@ impl WindowDelegateState {
       window: Arc::downgrade(window),
       initial_fullscreen,
       previous_position: None,
+      resize_origin: None,
       previous_scale_factor: scale_factor,
       is_checking_zoomed_in: false,
     };
@@ -183,6 +188,10 @@ lazy_static! {
       sel!(windowWillClose:),
       window_will_close as extern "C" fn(&Object, Sel, id),
     );
+    decl.add_method(
+      sel!(windowWillResize:toSize:),
+      window_will_resize_to_size as extern "C" fn(&Object, Sel, id, NSSize) -> NSSize,
+    );
     decl.add_method(
       sel!(windowDidResize:),
       window_did_resize as extern "C" fn(&Object, Sel, id),
@@ -250,6 +259,14 @@ lazy_static! {
       sel!(windowDidFailToEnterFullScreen:),
       window_did_fail_to_enter_fullscreen as extern "C" fn(&Object, Sel, id),
     );
+    decl.add_method(
+      sel!(windowDidChangeScreen:),
+      window_did_change_screen as extern "C" fn(&Object, Sel, id),
+    );
+    decl.add_method(
+      sel!(windowDidChangeOcclusionState:),
+      window_did_change_occlusion_state as extern "C" fn(&Object, Sel, id),
+    );
     decl.add_method(
       sel!(effectiveAppearanceDidChange:),
       effective_appearance_did_change as extern "C" fn(&Object, Sel, id),
@@ -338,9 +355,42 @@ extern "C" fn window_will_close(this: &Object, _: Sel, _: id) {
   trace!("Completed `windowWillClose:`");
 }
 
+// Only called for resizes performed by the user.
+extern "C" fn window_will_resize_to_size(
+  this: &Object,
+  _: Sel,
+  _: id,
+  frame_size: NSSize,
+) -> NSSize {
+  trace!("Triggered `windowWillResize:toSize:`");
+  with_state(this, |state| {
+    let resize_behavior = state
+      .with_window(|window| window.shared_state.lock().unwrap().resize_behavior)
+      .unwrap_or_default();
+    // AppKit keeps the edge opposite to the one being dragged in place, so we compute
+    // where the origin (the bottom-left corner) must go to keep the requested anchor instead.
+    let frame = unsafe { NSWindow::frame(*state.ns_window) };
+    let dw = frame.size.width - frame_size.width;
+    let dh = frame.size.height - frame_size.height;
+    state.resize_origin = match resize_behavior {
+      ResizeBehavior::KeepTopLeft => Some(NSPoint::new(frame.origin.x, frame.origin.y + dh)),
+      ResizeBehavior::KeepCenter => Some(NSPoint::new(
+        frame.origin.x + dw / 2.0,
+        frame.origin.y + dh / 2.0,
+      )),
+      ResizeBehavior::Default => None,
+    };
+  });
+  trace!("Completed `windowWillResize:toSize:`");
+  frame_size
+}
+
 extern "C" fn window_did_resize(this: &Object, _: Sel, _: id) {
   trace!("Triggered `windowDidResize:`");
   with_state(this, |state| {
+    if let Some(origin) = state.resize_origin.take() {
+      unsafe { state.ns_window.setFrameOrigin_(origin) };
+    }
     if !state.is_checking_zoomed_in {
       state.emit_resize_event();
       state.emit_move_event();
@@ -647,6 +697,32 @@ extern "C" fn window_did_fail_to_enter_fullscreen(this: &Object, _: Sel, _: id)
   trace!("Completed `windowDidFailToEnterFullscreen:`");
 }
 
+extern "C" fn window_did_change_screen(this: &Object, _: Sel, _: id) {
+  trace!("Triggered `windowDidChangeScreen:`");
+  with_state(this, |state| {
+    if let Some(window) = state.window.upgrade() {
+      let new_monitor = window.current_monitor_inner();
+      state.emit_event(WindowEvent::MonitorChanged { new_monitor });
+    }
+  });
+  trace!("Completed `windowDidChangeScreen:`");
+}
+
+extern "C" fn window_did_change_occlusion_state(this: &Object, _: Sel, _: id) {
+  trace!("Triggered `windowDidChangeOcclusionState:`");
+  with_state(this, |state| {
+    let occlusion_state = unsafe { state.ns_window.occlusionState() };
+    let occlusion_state =
+      if occlusion_state.contains(NSWindowOcclusionState::NSWindowOcclusionStateVisible) {
+        OcclusionState::Visible
+      } else {
+        OcclusionState::Hidden
+      };
+    state.emit_event(WindowEvent::OcclusionStateChanged(occlusion_state));
+  });
+  trace!("Completed `windowDidChangeOcclusionState:`");
+}
+
 // Observe theme change
 extern "C" fn effective_appearance_did_change(this: &Object, _: Sel, _: id) {
   trace!("Triggered `effectiveAppearDidChange:`");
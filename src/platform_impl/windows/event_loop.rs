@@ -23,20 +23,24 @@ use windows::{
   core::{s, PCWSTR},
   Win32::{
     Foundation::{
-      BOOL, HANDLE, HINSTANCE, HMODULE, HWND, LPARAM, LRESULT, POINT, RECT, WAIT_TIMEOUT, WPARAM,
+      CloseHandle, BOOL, HANDLE, HINSTANCE, HMODULE, HWND, LPARAM, LRESULT, POINT, RECT,
+      WAIT_OBJECT_0, WAIT_TIMEOUT, WPARAM,
     },
     Graphics::Gdi::*,
     System::{
       LibraryLoader::GetModuleHandleW,
       Ole::{IDropTarget, RevokeDragDrop},
-      Threading::{GetCurrentThreadId, INFINITE},
+      Threading::{
+        CreateWaitableTimerExW, GetCurrentThreadId, SetWaitableTimer,
+        CREATE_WAITABLE_TIMER_HIGH_RESOLUTION, INFINITE, TIMER_ALL_ACCESS,
+      },
     },
     UI::{
       Controls::{self as win32c, HOVER_DEFAULT},
       Input::{KeyboardAndMouse::*, Pointer::*, Touch::*, *},
       Shell::{
         DefSubclassProc, RemoveWindowSubclass, SHAppBarMessage, SetWindowSubclass, ABE_BOTTOM,
-        ABE_LEFT, ABE_RIGHT, ABE_TOP, ABM_GETAUTOHIDEBAR, APPBARDATA,
+        ABE_LEFT, ABE_RIGHT, ABE_TOP, ABM_GETAUTOHIDEBAR, ABN_FULLSCREENAPP, APPBARDATA,
       },
       WindowsAndMessaging::{self as win32wm, *},
     },
@@ -47,7 +51,10 @@ use crate::{
   dpi::{PhysicalPosition, PhysicalSize, PixelUnit},
   error::ExternalError,
   event::{DeviceEvent, Event, Force, RawKeyEvent, Touch, TouchPhase, WindowEvent},
-  event_loop::{ControlFlow, DeviceEventFilter, EventLoopClosed, EventLoopWindowTarget as RootELW},
+  event_loop::{
+    ControlFlow, ControlFlowPrecision, DeviceEventFilter, EventLoopClosed,
+    EventLoopWindowTarget as RootELW,
+  },
   keyboard::{KeyCode, ModifiersState},
   monitor::MonitorHandle as RootMonitorHandle,
   platform_impl::platform::{
@@ -62,7 +69,7 @@ use crate::{
     window_state::{CursorFlags, WindowFlags, WindowState},
     wrap_device_id, WindowId, DEVICE_ID,
   },
-  window::{Fullscreen, Theme, WindowId as RootWindowId},
+  window::{Fullscreen, ResizeBehavior, Theme, WindowId as RootWindowId},
 };
 use runner::{EventLoopRunner, EventLoopRunnerShared};
 
@@ -204,6 +211,8 @@ impl<T: 'static> EventLoop<T> {
           runner_shared,
           preferred_theme: Arc::new(Mutex::new(attributes.preferred_theme)),
         },
+        event_filter: Default::default(),
+        idle_callback: Default::default(),
         _marker: PhantomData,
       },
       msg_hook: attributes.msg_hook.take(),
@@ -302,8 +311,7 @@ impl<T> EventLoopWindowTarget<T> {
   }
 
   pub fn primary_monitor(&self) -> Option<RootMonitorHandle> {
-    let monitor = monitor::primary_monitor();
-    Some(RootMonitorHandle { inner: monitor })
+    monitor::primary_monitor().map(|inner| RootMonitorHandle { inner })
   }
 
   pub fn monitor_from_point(&self, x: f64, y: f64) -> Option<MonitorHandle> {
@@ -331,6 +339,18 @@ impl<T> EventLoopWindowTarget<T> {
     util::cursor_position().map_err(Into::into)
   }
 
+  #[inline]
+  pub fn request_redraw_all(&self) {
+    self.runner_shared.owned_windows(|window| unsafe {
+      let _ = RedrawWindow(window, None, HRGN::default(), RDW_INTERNALPAINT);
+    });
+  }
+
+  #[inline]
+  pub fn set_control_flow_precision(&self, precision: ControlFlowPrecision) {
+    self.runner_shared.set_control_flow_precision(precision);
+  }
+
   #[inline]
   pub fn set_theme(&self, theme: Option<Theme>) {
     *self.preferred_theme.lock() = theme;
@@ -385,7 +405,17 @@ fn wait_thread(parent_thread_id: u32, msg_window_id: HWND) {
       LPARAM(cur_thread_id as _),
     );
 
+    // Only available on Windows 10 1803 and later.
+    let high_resolution_timer = CreateWaitableTimerExW(
+      None,
+      PCWSTR::null(),
+      CREATE_WAITABLE_TIMER_HIGH_RESOLUTION,
+      TIMER_ALL_ACCESS.0,
+    )
+    .ok();
+
     let mut wait_until_opt = None;
+    let mut high_resolution = false;
     'main: loop {
       // Zeroing out the message ensures that the `WaitUntilInstantBox` doesn't get
       // double-freed if `MsgWaitForMultipleObjectsEx` returns early and there aren't
@@ -406,6 +436,7 @@ fn wait_thread(parent_thread_id: u32, msg_window_id: HWND) {
 
       if msg.message == *WAIT_UNTIL_MSG_ID {
         wait_until_opt = Some(*WaitUntilInstantBox::from_raw(msg.lParam.0 as *mut _));
+        high_resolution = msg.wParam.0 != 0;
       } else if msg.message == *CANCEL_WAIT_UNTIL_MSG_ID {
         wait_until_opt = None;
       }
@@ -413,15 +444,35 @@ fn wait_thread(parent_thread_id: u32, msg_window_id: HWND) {
       if let Some(wait_until) = wait_until_opt {
         let now = Instant::now();
         if now < wait_until {
-          // MsgWaitForMultipleObjects tends to overshoot just a little bit. We subtract
-          // 1 millisecond from the requested time and spinlock for the remainder to
-          // compensate for that.
-          let resume_reason = MsgWaitForMultipleObjectsEx(
-            None,
-            dur2timeout(wait_until - now).saturating_sub(1),
-            QS_ALLEVENTS,
-            MWMO_INPUTAVAILABLE,
-          );
+          // A negative due time is relative, in 100 nanosecond intervals.
+          let due_time = -i64::try_from((wait_until - now).as_nanos() / 100).unwrap_or(i64::MAX);
+          let timer = high_resolution_timer.filter(|timer| {
+            high_resolution && SetWaitableTimer(*timer, &due_time, 0, None, None, false).is_ok()
+          });
+          let resume_reason = match timer {
+            Some(timer) => {
+              let resume_reason = MsgWaitForMultipleObjectsEx(
+                Some(&[timer]),
+                INFINITE,
+                QS_ALLEVENTS,
+                MWMO_INPUTAVAILABLE,
+              );
+              if resume_reason == WAIT_OBJECT_0 {
+                WAIT_TIMEOUT
+              } else {
+                resume_reason
+              }
+            }
+            // MsgWaitForMultipleObjects tends to overshoot just a little bit. We subtract
+            // 1 millisecond from the requested time and spinlock for the remainder to
+            // compensate for that.
+            None => MsgWaitForMultipleObjectsEx(
+              None,
+              dur2timeout(wait_until - now).saturating_sub(1),
+              QS_ALLEVENTS,
+              MWMO_INPUTAVAILABLE,
+            ),
+          };
           if resume_reason == WAIT_TIMEOUT {
             let _ = PostMessageW(
               msg_window_id,
@@ -442,6 +493,10 @@ fn wait_thread(parent_thread_id: u32, msg_window_id: HWND) {
         }
       }
     }
+
+    if let Some(timer) = high_resolution_timer {
+      let _ = CloseHandle(timer);
+    }
   }
 }
 
@@ -601,7 +656,7 @@ lazy_static! {
         }
     };
     /// lparam points to a `Box<Instant>` signifying the time `PROCESS_NEW_EVENTS_MSG_ID` should
-    /// be sent.
+    /// be sent, wparam is whether to use the high resolution timer.
     static ref WAIT_UNTIL_MSG_ID: u32 = {
         unsafe {
             RegisterWindowMessageA(s!("Tao::WaitUntil"))
@@ -634,6 +689,12 @@ lazy_static! {
     pub static ref S_U_TASKBAR_RESTART: u32 = unsafe {
       RegisterWindowMessageA(s!("TaskbarCreated"))
     };
+    /// Sent by the shell to always on top windows registered with `util::set_app_bar_callback`.
+    /// WPARAM is the `ABN_*` notification, for `ABN_FULLSCREENAPP` LPARAM is whether a
+    /// fullscreen app is opening.
+    pub static ref APP_BAR_MSG_ID: u32 = unsafe {
+        RegisterWindowMessageA(s!("Tao::AppBar"))
+    };
     static ref THREAD_EVENT_TARGET_WINDOW_CLASS: Vec<u16> = unsafe {
         let class_name= util::encode_wide("Tao Thread Event Target");
 
@@ -845,10 +906,11 @@ unsafe fn process_control_flow<T: 'static>(runner: &EventLoopRunner<T>) {
     }
     ControlFlow::Wait => (),
     ControlFlow::WaitUntil(until) => {
+      let high_resolution = runner.control_flow_precision() == ControlFlowPrecision::HighResolution;
       let _ = PostThreadMessageW(
         runner.wait_thread_id(),
         *WAIT_UNTIL_MSG_ID,
-        WPARAM(0),
+        WPARAM(high_resolution as _),
         LPARAM(Box::into_raw(WaitUntilInstantBox::new(until)) as _),
       );
     }
@@ -1010,21 +1072,20 @@ unsafe fn public_window_callback_inner<T: 'static>(
     .unwrap_or_else(|| result = ProcResult::Value(LRESULT(-1)));
 
   let ime_callback = || {
-    use crate::event::WindowEvent::ReceivedImeText;
     let is_ime_related = is_msg_ime_related(msg);
     if !is_ime_related {
       return;
     }
-    let text = {
+    let events = {
       let mut window_state = subclass_input.window_state.lock();
       window_state
         .ime_handler
         .process_message(window, msg, wparam, lparam, &mut result)
     };
-    if let Some(str) = text {
+    for event in events {
       subclass_input.send_event(Event::WindowEvent {
         window_id: RootWindowId(WindowId(window.0 as _)),
-        event: ReceivedImeText(str),
+        event,
       });
     }
   };
@@ -1082,6 +1143,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
     win32wm::WM_DESTROY => {
       use crate::event::WindowEvent::Destroyed;
       let _ = RevokeDragDrop(window);
+      util::set_app_bar_callback(window, None);
       subclass_input.send_event(Event::WindowEvent {
         window_id: RootWindowId(WindowId(window.0 as _)),
         event: Destroyed,
@@ -1232,6 +1294,21 @@ unsafe fn public_window_callback_inner<T: 'static>(
         });
       }
 
+      let new_monitor = monitor::current_monitor(window);
+      let monitor_changed = {
+        let mut w = subclass_input.window_state.lock();
+        let previous_monitor = w.current_monitor.replace(new_monitor.clone());
+        matches!(previous_monitor, Some(previous) if previous != new_monitor)
+      };
+      if monitor_changed {
+        subclass_input.send_event(Event::WindowEvent {
+          window_id: RootWindowId(WindowId(window.0 as _)),
+          event: WindowEvent::MonitorChanged {
+            new_monitor: RootMonitorHandle { inner: new_monitor },
+          },
+        });
+      }
+
       // This is necessary for us to still get sent WM_SIZE.
       result = ProcResult::DefSubclassProc;
     }
@@ -1263,6 +1340,76 @@ unsafe fn public_window_callback_inner<T: 'static>(
       result = ProcResult::Value(LRESULT(0));
     }
 
+    win32wm::WM_SIZING => {
+      let (resize_behavior, resize_increments, scale_factor) = {
+        let window_state = subclass_input.window_state.lock();
+        (
+          window_state.resize_behavior,
+          window_state.resize_increments,
+          window_state.scale_factor,
+        )
+      };
+      // `lparam` points to the proposed window rect.
+      let rect = &mut *(lparam.0 as *mut RECT);
+      result = ProcResult::DefWindowProc;
+
+      let mut current = RECT::default();
+      let mut client = RECT::default();
+      if let Some(increments) = resize_increments {
+        let increments: PhysicalSize<i32> = increments.to_physical(scale_factor);
+        if increments.width > 0
+          && increments.height > 0
+          && GetWindowRect(window, &mut current).is_ok()
+          && GetClientRect(window, &mut client).is_ok()
+        {
+          // The frame doesn't change while resizing, so snap the proposed client size and
+          // move the edge that is being dragged.
+          let frame_width = (current.right - current.left) - (client.right - client.left);
+          let frame_height = (current.bottom - current.top) - (client.bottom - client.top);
+          let snap = |size: i32, increment: i32| {
+            ((size as f64 / increment as f64).round() as i32 * increment).max(increment)
+          };
+          let width = snap(rect.right - rect.left - frame_width, increments.width) + frame_width;
+          let height =
+            snap(rect.bottom - rect.top - frame_height, increments.height) + frame_height;
+
+          let edge = wparam.0 as u32;
+          if matches!(edge, WMSZ_LEFT | WMSZ_TOPLEFT | WMSZ_BOTTOMLEFT) {
+            rect.left = rect.right - width;
+          } else {
+            rect.right = rect.left + width;
+          }
+          if matches!(edge, WMSZ_TOP | WMSZ_TOPLEFT | WMSZ_TOPRIGHT) {
+            rect.top = rect.bottom - height;
+          } else {
+            rect.bottom = rect.top + height;
+          }
+          result = ProcResult::Value(LRESULT(1));
+        }
+      }
+
+      if resize_behavior != ResizeBehavior::Default && GetWindowRect(window, &mut current).is_ok() {
+        // Re-anchor the proposed rect so that the requested origin stays in place no matter
+        // which edge is being dragged.
+        let width = rect.right - rect.left;
+        let height = rect.bottom - rect.top;
+        match resize_behavior {
+          ResizeBehavior::KeepTopLeft => {
+            rect.left = current.left;
+            rect.top = current.top;
+          }
+          ResizeBehavior::KeepCenter => {
+            rect.left = current.left - (width - (current.right - current.left)) / 2;
+            rect.top = current.top - (height - (current.bottom - current.top)) / 2;
+          }
+          ResizeBehavior::Default => (),
+        }
+        rect.right = rect.left + width;
+        rect.bottom = rect.top + height;
+        result = ProcResult::Value(LRESULT(1));
+      }
+    }
+
     // this is necessary for us to maintain minimize/restore state
     win32wm::WM_SYSCOMMAND => {
       if wparam.0 == SC_RESTORE as _ {
@@ -1799,7 +1946,7 @@ unsafe fn public_window_callback_inner<T: 'static>(
         // `WM_MOUSEMOVE` seems to come after `WM_SETCURSOR` for a given cursor movement.
         let in_client_area = u32::from(util::LOWORD(lparam.0 as u32)) == HTCLIENT;
         if in_client_area {
-          Some(window_state.mouse.cursor)
+          Some(window_state.mouse.effective_cursor())
         } else {
           None
         }
@@ -1826,43 +1973,49 @@ unsafe fn public_window_callback_inner<T: 'static>(
 
       let size_constraints = window_state.size_constraints;
 
+      // The system fills `MINMAXINFO` with its defaults (`SM_CXMINTRACK` and friends) before
+      // sending this message, so unconstrained axes are left untouched to keep those defaults.
       if size_constraints.has_min() {
         let min_size = PhysicalSize::new(
           size_constraints
             .min_width
-            .unwrap_or_else(|| PixelUnit::Physical(GetSystemMetrics(SM_CXMINTRACK).into()))
+            .unwrap_or(PixelUnit::MIN)
             .to_physical(window_state.scale_factor)
             .0,
           size_constraints
             .min_height
-            .unwrap_or_else(|| PixelUnit::Physical(GetSystemMetrics(SM_CYMINTRACK).into()))
+            .unwrap_or(PixelUnit::MIN)
             .to_physical(window_state.scale_factor)
             .0,
         );
         let (width, height): (u32, u32) = util::adjust_size(window, min_size, is_decorated).into();
-        (*mmi).ptMinTrackSize = POINT {
-          x: width as i32,
-          y: height as i32,
-        };
+        if size_constraints.min_width.is_some() {
+          (*mmi).ptMinTrackSize.x = width as i32;
+        }
+        if size_constraints.min_height.is_some() {
+          (*mmi).ptMinTrackSize.y = height as i32;
+        }
       }
       if size_constraints.has_max() {
         let max_size = PhysicalSize::new(
           size_constraints
             .max_width
-            .unwrap_or_else(|| PixelUnit::Physical(GetSystemMetrics(SM_CXMAXTRACK).into()))
+            .unwrap_or(PixelUnit::MIN)
             .to_physical(window_state.scale_factor)
             .0,
           size_constraints
             .max_height
-            .unwrap_or_else(|| PixelUnit::Physical(GetSystemMetrics(SM_CYMAXTRACK).into()))
+            .unwrap_or(PixelUnit::MIN)
             .to_physical(window_state.scale_factor)
             .0,
         );
         let (width, height): (u32, u32) = util::adjust_size(window, max_size, is_decorated).into();
-        (*mmi).ptMaxTrackSize = POINT {
-          x: width as i32,
-          y: height as i32,
-        };
+        if size_constraints.max_width.is_some() {
+          (*mmi).ptMaxTrackSize.x = width as i32;
+        }
+        if size_constraints.max_height.is_some() {
+          (*mmi).ptMaxTrackSize.y = height as i32;
+        }
       }
 
       result = ProcResult::Value(LRESULT(0));
@@ -2169,9 +2322,21 @@ unsafe fn public_window_callback_inner<T: 'static>(
         let mut rect = RECT::default();
         let _ = GetWindowRect(window, &mut rect);
 
-        let padded_border = GetSystemMetrics(SM_CXPADDEDBORDER);
-        let border_x = GetSystemMetrics(SM_CXFRAME) + padded_border;
-        let border_y = GetSystemMetrics(SM_CYFRAME) + padded_border;
+        let (border_x, border_y) = match window_state.resize_border_width {
+          Some(width) => {
+            let border = PixelUnit::Logical(width.into())
+              .to_physical::<i32>(window_state.scale_factor)
+              .0;
+            (border, border)
+          }
+          None => {
+            let padded_border = GetSystemMetrics(SM_CXPADDEDBORDER);
+            (
+              GetSystemMetrics(SM_CXFRAME) + padded_border,
+              GetSystemMetrics(SM_CYFRAME) + padded_border,
+            )
+          }
+        };
 
         let hit_result = crate::window::hit_test(
           (rect.left, rect.top, rect.right, rect.bottom),
@@ -2211,6 +2376,29 @@ unsafe fn public_window_callback_inner<T: 'static>(
       } else if msg == *S_U_TASKBAR_RESTART {
         let window_state = subclass_input.window_state.lock();
         let _ = set_skip_taskbar(window, window_state.skip_taskbar);
+      } else if msg == *APP_BAR_MSG_ID {
+        let window_flags = subclass_input.window_state.lock().window_flags();
+        let yields_to_fullscreen = window_flags.contains(WindowFlags::ALWAYS_ON_TOP)
+          && !window_flags.intersects(
+            WindowFlags::FORCE_ABOVE_FULLSCREEN
+              | WindowFlags::MARKER_EXCLUSIVE_FULLSCREEN
+              | WindowFlags::MARKER_BORDERLESS_FULLSCREEN,
+          );
+        if wparam.0 as u32 == ABN_FULLSCREENAPP && yields_to_fullscreen {
+          let flags = SWP_NOMOVE | SWP_NOSIZE | SWP_NOACTIVATE;
+          if lparam.0 != 0 {
+            // Leave the topmost band and move right below the fullscreen app, which is
+            // the foreground window at this point.
+            let _ = SetWindowPos(window, HWND_NOTOPMOST, 0, 0, 0, 0, flags);
+            let foreground = GetForegroundWindow();
+            if !foreground.is_invalid() && foreground != window {
+              let _ = SetWindowPos(window, foreground, 0, 0, 0, 0, flags);
+            }
+          } else {
+            let _ = SetWindowPos(window, HWND_TOPMOST, 0, 0, 0, 0, flags);
+          }
+        }
+        result = ProcResult::Value(LRESULT(0));
       }
     }
   };
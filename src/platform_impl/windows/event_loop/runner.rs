@@ -19,7 +19,7 @@ use windows::Win32::{
 use crate::{
   dpi::PhysicalSize,
   event::{Event, StartCause, WindowEvent},
-  event_loop::ControlFlow,
+  event_loop::{ControlFlow, ControlFlowPrecision},
   platform_impl::platform::util,
   window::WindowId,
 };
@@ -31,6 +31,7 @@ pub(crate) struct EventLoopRunner<T: 'static> {
   wait_thread_id: u32,
 
   control_flow: Cell<ControlFlow>,
+  control_flow_precision: Cell<ControlFlowPrecision>,
   runner_state: Cell<RunnerState>,
   last_events_cleared: Cell<Instant>,
 
@@ -73,6 +74,7 @@ impl<T> EventLoopRunner<T> {
       wait_thread_id,
       runner_state: Cell::new(RunnerState::Uninitialized),
       control_flow: Cell::new(ControlFlow::Poll),
+      control_flow_precision: Cell::new(ControlFlowPrecision::default()),
       panic_error: Cell::new(None),
       last_events_cleared: Cell::new(Instant::now()),
       event_handler: Cell::new(None),
@@ -136,6 +138,14 @@ impl<T> EventLoopRunner<T> {
     self.control_flow.get()
   }
 
+  pub fn control_flow_precision(&self) -> ControlFlowPrecision {
+    self.control_flow_precision.get()
+  }
+
+  pub fn set_control_flow_precision(&self, precision: ControlFlowPrecision) {
+    self.control_flow_precision.set(precision);
+  }
+
   pub fn handling_events(&self) -> bool {
     self.runner_state.get() != RunnerState::Idle
   }
@@ -1,11 +1,20 @@
-use std::mem::MaybeUninit;
+use std::{ffi::c_void, mem::MaybeUninit};
 
 use windows::Win32::{
   Foundation::{HWND, LPARAM, LRESULT, WPARAM},
-  UI::WindowsAndMessaging::{self as win32wm, *},
+  UI::{
+    Input::Ime::{
+      ImmGetCompositionStringW, ImmGetContext, ImmReleaseContext, GCS_COMPSTR, GCS_CURSORPOS,
+      GCS_RESULTSTR, HIMC, IME_COMPOSITION_STRING,
+    },
+    WindowsAndMessaging::{self as win32wm, *},
+  },
 };
 
-use crate::platform_impl::platform::event_loop::ProcResult;
+use crate::{
+  event::{ImeEvent, WindowEvent},
+  platform_impl::platform::event_loop::ProcResult,
+};
 
 pub fn is_msg_ime_related(msg_kind: u32) -> bool {
   matches!(
@@ -24,12 +33,16 @@ pub struct MinimalIme {
   // True if we're currently receiving messages belonging to a finished IME session.
   getting_ime_text: bool,
 
+  // True if the current IME session produced a result string that will arrive as `WM_CHAR`s.
+  result_pending: bool,
+
   utf16parts: Vec<u16>,
 }
 impl Default for MinimalIme {
   fn default() -> Self {
     MinimalIme {
       getting_ime_text: false,
+      result_pending: false,
       utf16parts: Vec::with_capacity(16),
     }
   }
@@ -40,12 +53,33 @@ impl MinimalIme {
     hwnd: HWND,
     msg_kind: u32,
     wparam: WPARAM,
-    _lparam: LPARAM,
+    lparam: LPARAM,
     result: &mut ProcResult,
-  ) -> Option<String> {
+  ) -> Vec<WindowEvent<'static>> {
+    let mut events = Vec::new();
     match msg_kind {
+      win32wm::WM_IME_STARTCOMPOSITION => {
+        self.result_pending = false;
+        events.push(WindowEvent::Ime(ImeEvent::Enabled));
+      }
+      win32wm::WM_IME_COMPOSITION => {
+        let flags = lparam.0 as u32;
+        if flags & GCS_RESULTSTR.0 != 0 {
+          self.result_pending = true;
+        }
+        if flags & GCS_COMPSTR.0 != 0 {
+          if let Some((text, cursor)) = unsafe { get_composition_string(hwnd, flags) } {
+            events.push(WindowEvent::Ime(ImeEvent::Preedit(text, cursor)));
+          }
+        }
+      }
       win32wm::WM_IME_ENDCOMPOSITION => {
         self.getting_ime_text = true;
+        events.push(WindowEvent::Ime(ImeEvent::Preedit(String::new(), None)));
+        // The composition was cancelled, no `WM_CHAR` will follow to commit it.
+        if !self.result_pending {
+          events.push(WindowEvent::Ime(ImeEvent::Disabled));
+        }
       }
       win32wm::WM_CHAR | win32wm::WM_SYSCHAR => {
         *result = ProcResult::Value(LRESULT(0));
@@ -71,18 +105,74 @@ impl MinimalIme {
             }
           }
           if !more_char_coming {
-            let result = String::from_utf16(&self.utf16parts).ok();
+            let text = String::from_utf16(&self.utf16parts).ok();
             self.utf16parts.clear();
             self.getting_ime_text = false;
-            return result;
+            if let Some(text) = text {
+              if self.result_pending {
+                self.result_pending = false;
+                events.push(WindowEvent::Ime(ImeEvent::Commit(text.clone())));
+                events.push(WindowEvent::Ime(ImeEvent::Disabled));
+              }
+              events.push(WindowEvent::ReceivedImeText(text));
+            }
           }
-        } else {
-          return String::from_utf16(&[wparam.0 as u16]).ok();
+        } else if let Ok(text) = String::from_utf16(&[wparam.0 as u16]) {
+          events.push(WindowEvent::ReceivedImeText(text));
         }
       }
       _ => (),
     }
 
+    events
+  }
+}
+
+/// Reads the current composition string and, if requested in `flags`, the cursor position
+/// converted to a byte offset into the returned string.
+unsafe fn get_composition_string(
+  hwnd: HWND,
+  flags: u32,
+) -> Option<(String, Option<(usize, usize)>)> {
+  let himc = ImmGetContext(hwnd);
+  if himc.is_invalid() {
+    return None;
+  }
+
+  let text = read_composition_string(himc, GCS_COMPSTR);
+  let cursor = if flags & GCS_CURSORPOS.0 != 0 {
+    let pos = ImmGetCompositionStringW(himc, GCS_CURSORPOS, None, 0);
+    usize::try_from(pos).ok()
+  } else {
     None
+  };
+
+  let _ = ImmReleaseContext(hwnd, himc);
+
+  let utf16 = text?;
+  let text = String::from_utf16(&utf16).ok()?;
+  let cursor = cursor.map(|pos| {
+    let byte_offset = String::from_utf16_lossy(&utf16[..pos.min(utf16.len())]).len();
+    (byte_offset, byte_offset)
+  });
+  Some((text, cursor))
+}
+
+unsafe fn read_composition_string(himc: HIMC, kind: IME_COMPOSITION_STRING) -> Option<Vec<u16>> {
+  // The returned length is in bytes.
+  let len = ImmGetCompositionStringW(himc, kind, None, 0);
+  if len < 0 {
+    return None;
+  }
+
+  let mut buffer = vec![0u16; len as usize / 2];
+  if len > 0 {
+    ImmGetCompositionStringW(
+      himc,
+      kind,
+      Some(buffer.as_mut_ptr() as *mut c_void),
+      len as u32,
+    );
   }
+  Some(buffer)
 }
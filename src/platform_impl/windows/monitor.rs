@@ -5,6 +5,12 @@
 use windows::{
   core::PCWSTR,
   Win32::{
+    Devices::Display::{
+      DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+      DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+      DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_PATH_INFO,
+      DISPLAYCONFIG_SOURCE_DEVICE_NAME, DISPLAYCONFIG_TARGET_DEVICE_NAME, QDC_ONLY_ACTIVE_PATHS,
+    },
     Foundation::{BOOL, HWND, LPARAM, POINT, RECT},
     Graphics::Gdi::*,
   },
@@ -112,10 +118,18 @@ pub fn available_monitors() -> VecDeque<MonitorHandle> {
   monitors
 }
 
-pub fn primary_monitor() -> MonitorHandle {
+pub fn primary_monitor() -> Option<MonitorHandle> {
   const ORIGIN: POINT = POINT { x: 0, y: 0 };
   let hmonitor = unsafe { MonitorFromPoint(ORIGIN, MONITOR_DEFAULTTOPRIMARY) };
-  MonitorHandle::new(hmonitor)
+  valid_monitor(hmonitor)
+}
+
+fn valid_monitor(hmonitor: HMONITOR) -> Option<MonitorHandle> {
+  // There is no monitor attached at all, e.g. in a headless session.
+  if hmonitor.is_invalid() {
+    return None;
+  }
+  Some(MonitorHandle::new(hmonitor))
 }
 
 pub fn current_monitor(hwnd: HWND) -> MonitorHandle {
@@ -146,8 +160,7 @@ impl Window {
   }
 
   pub fn primary_monitor(&self) -> Option<RootMonitorHandle> {
-    let monitor = primary_monitor();
-    Some(RootMonitorHandle { inner: monitor })
+    primary_monitor().map(|inner| RootMonitorHandle { inner })
   }
 
   pub fn monitor_from_point(&self, x: f64, y: f64) -> Option<RootMonitorHandle> {
@@ -155,6 +168,78 @@ impl Window {
   }
 }
 
+/// Finds the active display path whose source is the GDI device `device_name` (e.g.
+/// `\\.\DISPLAY1`) and returns the friendly name of the monitor it drives.
+unsafe fn get_friendly_name(device_name: &[u16]) -> Option<String> {
+  let mut path_count = 0;
+  let mut mode_count = 0;
+  if GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count).is_err() {
+    return None;
+  }
+
+  let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+  let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+  if QueryDisplayConfig(
+    QDC_ONLY_ACTIVE_PATHS,
+    &mut path_count,
+    paths.as_mut_ptr(),
+    &mut mode_count,
+    modes.as_mut_ptr(),
+    None,
+  )
+  .is_err()
+  {
+    return None;
+  }
+
+  let device_name_len = device_name
+    .iter()
+    .position(|&c| c == 0)
+    .unwrap_or(device_name.len());
+
+  for path in &paths[..path_count as usize] {
+    let mut source = DISPLAYCONFIG_SOURCE_DEVICE_NAME {
+      header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+        size: mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32,
+        adapterId: path.sourceInfo.adapterId,
+        id: path.sourceInfo.id,
+      },
+      ..Default::default()
+    };
+    if DisplayConfigGetDeviceInfo(&mut source.header) != 0 {
+      continue;
+    }
+    let source_name_len = source
+      .viewGdiDeviceName
+      .iter()
+      .position(|&c| c == 0)
+      .unwrap_or(source.viewGdiDeviceName.len());
+    if source.viewGdiDeviceName[..source_name_len] != device_name[..device_name_len] {
+      continue;
+    }
+
+    let mut target = DISPLAYCONFIG_TARGET_DEVICE_NAME {
+      header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        size: mem::size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME>() as u32,
+        adapterId: path.targetInfo.adapterId,
+        id: path.targetInfo.id,
+      },
+      ..Default::default()
+    };
+    if DisplayConfigGetDeviceInfo(&mut target.header) != 0 {
+      return None;
+    }
+    let name =
+      util::wchar_ptr_to_string(PCWSTR::from_raw(target.monitorFriendlyDeviceName.as_ptr()));
+    // Built-in panels and some adapters don't report an EDID name.
+    return if name.is_empty() { None } else { Some(name) };
+  }
+
+  None
+}
+
 pub(crate) fn get_monitor_info(hmonitor: HMONITOR) -> Result<MONITORINFOEXW, io::Error> {
   let mut monitor_info = MONITORINFOEXW::default();
   monitor_info.monitorInfo.cbSize = mem::size_of::<MONITORINFOEXW>() as u32;
@@ -178,15 +263,19 @@ impl MonitorHandle {
 
   #[inline]
   pub fn name(&self) -> Option<String> {
-    let monitor_info = get_monitor_info(self.hmonitor()).unwrap();
-    Some(util::wchar_ptr_to_string(PCWSTR::from_raw(
-      monitor_info.szDevice.as_ptr(),
-    )))
+    let monitor_info = get_monitor_info(self.hmonitor()).ok()?;
+    // Virtual and some built-in displays don't report a friendly name.
+    unsafe { get_friendly_name(&monitor_info.szDevice) }.or_else(|| {
+      Some(util::wchar_ptr_to_string(PCWSTR::from_raw(
+        monitor_info.szDevice.as_ptr(),
+      )))
+    })
   }
 
   #[inline]
   pub fn native_identifier(&self) -> String {
-    self.name().unwrap()
+    let monitor_info = get_monitor_info(self.hmonitor()).unwrap();
+    util::wchar_ptr_to_string(PCWSTR::from_raw(monitor_info.szDevice.as_ptr()))
   }
 
   #[inline]
@@ -219,6 +308,52 @@ impl MonitorHandle {
     dpi_to_scale_factor(get_monitor_dpi(self.hmonitor()).unwrap_or(96))
   }
 
+  #[inline]
+  pub fn physical_size_mm(&self) -> (u32, u32) {
+    let monitor_info = match get_monitor_info(self.hmonitor()) {
+      Ok(monitor_info) => monitor_info,
+      Err(_) => return (0, 0),
+    };
+    let device_name = PCWSTR::from_raw(monitor_info.szDevice.as_ptr());
+    unsafe {
+      let hdc = CreateDCW(device_name, device_name, PCWSTR::null(), None);
+      if hdc.is_invalid() {
+        return (0, 0);
+      }
+      let width = GetDeviceCaps(hdc, HORZSIZE);
+      let height = GetDeviceCaps(hdc, VERTSIZE);
+      let _ = DeleteDC(hdc);
+      (width.max(0) as u32, height.max(0) as u32)
+    }
+  }
+
+  #[inline]
+  pub fn refresh_rate_millihertz(&self) -> Option<u32> {
+    let monitor_info = get_monitor_info(self.hmonitor()).ok()?;
+    let device_name = PCWSTR::from_raw(monitor_info.szDevice.as_ptr());
+    unsafe {
+      let mut mode: DEVMODEW = mem::zeroed();
+      mode.dmSize = mem::size_of_val(&mode) as u16;
+      if !EnumDisplaySettingsExW(
+        device_name,
+        ENUM_CURRENT_SETTINGS,
+        &mut mode,
+        ENUM_DISPLAY_SETTINGS_FLAGS(0),
+      )
+      .as_bool()
+      {
+        return None;
+      }
+
+      // 0 and 1 mean the hardware's default refresh rate.
+      if mode.dmFields & DM_DISPLAYFREQUENCY != DM_DISPLAYFREQUENCY || mode.dmDisplayFrequency <= 1
+      {
+        return None;
+      }
+      Some(mode.dmDisplayFrequency * 1000)
+    }
+  }
+
   #[inline]
   pub fn video_modes(&self) -> impl Iterator<Item = RootVideoMode> {
     // EnumDisplaySettingsExW can return duplicate values (or some of the
@@ -263,3 +398,22 @@ impl MonitorHandle {
     modes.into_iter()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn no_monitor_is_none() {
+    assert!(valid_monitor(HMONITOR::default()).is_none());
+  }
+
+  #[test]
+  fn attached_monitor_is_some() {
+    let monitor = valid_monitor(HMONITOR(1 as _));
+    assert_eq!(
+      monitor.map(|monitor| monitor.hmonitor()),
+      Some(HMONITOR(1 as _))
+    );
+  }
+}
@@ -27,6 +27,7 @@ use windows::{
     UI::{
       HiDpi::*,
       Input::KeyboardAndMouse::*,
+      Shell::{SHAppBarMessage, ABM_NEW, ABM_REMOVE, APPBARDATA},
       WindowsAndMessaging::{self as win32wm, *},
     },
   },
@@ -163,6 +164,24 @@ pub fn adjust_window_rect_with_styles(
   }
 }
 
+/// Registers the window as an app bar that doesn't reserve any screen space, so that the shell
+/// sends it `callback_msg` with `ABN_FULLSCREENAPP` whenever a fullscreen app opens or closes.
+/// Passing `None` unregisters it.
+pub fn set_app_bar_callback(hwnd: HWND, callback_msg: Option<u32>) {
+  let mut data = APPBARDATA {
+    cbSize: mem::size_of::<APPBARDATA>() as _,
+    hWnd: hwnd,
+    uCallbackMessage: callback_msg.unwrap_or_default(),
+    ..Default::default()
+  };
+  let message = if callback_msg.is_some() {
+    ABM_NEW
+  } else {
+    ABM_REMOVE
+  };
+  unsafe { SHAppBarMessage(message, &mut data) };
+}
+
 pub fn set_cursor_hidden(hidden: bool) {
   static HIDDEN: AtomicBool = AtomicBool::new(false);
   let changed = HIDDEN.swap(hidden, Ordering::SeqCst) ^ hidden;
@@ -37,6 +37,7 @@ use windows::{
 use crate::{
   dpi::{PhysicalPosition, PhysicalSize, Position, Size},
   error::{ExternalError, NotSupportedError, OsError as RootOsError},
+  event::{Event, OcclusionState, WindowEvent},
   icon::Icon,
   monitor::MonitorHandle as RootMonitorHandle,
   platform_impl::platform::{
@@ -50,8 +51,9 @@ use crate::{
     OsError, Parent, PlatformSpecificWindowBuilderAttributes, WindowId,
   },
   window::{
-    CursorIcon, Fullscreen, ProgressBarState, ProgressState, ResizeDirection, Theme,
-    UserAttentionType, WindowAttributes, WindowSizeConstraints, RGBA,
+    CursorGrabMode, CursorIcon, Fullscreen, ProgressBarState, ProgressState, ResizeBehavior,
+    ResizeDirection, Theme, UserAttentionType, WindowAttributes, WindowId as RootWindowId,
+    WindowSizeConstraints, RGBA,
   },
 };
 
@@ -140,6 +142,13 @@ impl Window {
         };
 
         event_loop::subclass_window(win.window.0, subclass_input);
+
+        // Occlusion isn't tracked on Windows, the window is reported as visible once.
+        event_loop.runner_shared.send_event(Event::WindowEvent {
+          window_id: RootWindowId(win.id()),
+          event: WindowEvent::OcclusionStateChanged(OcclusionState::Visible),
+        });
+
         win
       })
     }
@@ -196,6 +205,18 @@ impl Window {
     }
   }
 
+  #[inline]
+  pub fn force_redraw_now(&self) {
+    unsafe {
+      let _ = RedrawWindow(
+        self.window.0,
+        None,
+        HRGN::default(),
+        RDW_INTERNALPAINT | RDW_UPDATENOW,
+      );
+    }
+  }
+
   #[inline]
   pub fn outer_position(&self) -> Result<PhysicalPosition<i32>, NotSupportedError> {
     unsafe { util::get_window_rect(self.window.0) }
@@ -212,8 +233,47 @@ impl Window {
     Ok(PhysicalPosition::new(position.x, position.y))
   }
 
+  #[inline]
+  pub fn screen_to_window(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let mut point = POINT {
+      x: position.x,
+      y: position.y,
+    };
+    if !unsafe { ScreenToClient(self.window.0, &mut point) }.as_bool() {
+      return Err(ExternalError::Os(os_error!(OsError::IoError(
+        io::Error::last_os_error()
+      ))));
+    }
+    Ok(PhysicalPosition::new(point.x, point.y))
+  }
+
+  #[inline]
+  pub fn window_to_screen(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    let mut point = POINT {
+      x: position.x,
+      y: position.y,
+    };
+    if !unsafe { ClientToScreen(self.window.0, &mut point) }.as_bool() {
+      return Err(ExternalError::Os(os_error!(OsError::IoError(
+        io::Error::last_os_error()
+      ))));
+    }
+    Ok(PhysicalPosition::new(point.x, point.y))
+  }
+
   #[inline]
   pub fn set_outer_position(&self, position: Position) {
+    let _ = self.set_outer_position_with_error(position);
+  }
+
+  #[inline]
+  pub fn set_outer_position_with_error(&self, position: Position) -> Result<(), RootOsError> {
     let (x, y): (i32, i32) = position.to_physical::<i32>(self.scale_factor()).into();
 
     let window_state = Arc::clone(&self.window_state);
@@ -225,7 +285,7 @@ impl Window {
     });
 
     unsafe {
-      let _ = SetWindowPos(
+      SetWindowPos(
         self.window.0,
         HWND::default(),
         x,
@@ -233,9 +293,11 @@ impl Window {
         0,
         0,
         SWP_ASYNCWINDOWPOS | SWP_NOZORDER | SWP_NOSIZE | SWP_NOACTIVATE,
-      );
+      )
+      .map_err(|e| os_error!(OsError::IoError(e.into())))?;
       let _ = InvalidateRgn(self.window.0, HRGN::default(), false);
     }
+    Ok(())
   }
 
   #[inline]
@@ -334,6 +396,21 @@ impl Window {
     });
   }
 
+  #[inline]
+  pub fn set_resize_behavior(&self, behavior: ResizeBehavior) {
+    self.window_state.lock().resize_behavior = behavior;
+  }
+
+  #[inline]
+  pub fn set_resize_increments(&self, increments: Option<Size>) {
+    self.window_state.lock().resize_increments = increments;
+  }
+
+  #[inline]
+  pub fn set_resize_border_width(&self, width: u32) {
+    self.window_state.lock().resize_border_width = Some(width);
+  }
+
   #[inline]
   pub fn set_minimizable(&self, minimizable: bool) {
     let window = self.window.0 .0 as isize;
@@ -429,7 +506,24 @@ impl Window {
 
   #[inline]
   pub fn set_cursor_icon(&self, cursor: CursorIcon) {
-    self.window_state.lock().mouse.cursor = cursor;
+    let cursor = {
+      let mut window_state = self.window_state.lock();
+      window_state.mouse.cursor = cursor;
+      window_state.mouse.effective_cursor()
+    };
+    self.thread_executor.execute_in_thread(move || unsafe {
+      let cursor = LoadCursorW(HMODULE::default(), cursor.to_windows_cursor()).unwrap_or_default();
+      SetCursor(cursor);
+    });
+  }
+
+  #[inline]
+  pub fn set_busy_cursor(&self, busy: bool) {
+    let cursor = {
+      let mut window_state = self.window_state.lock();
+      window_state.mouse.busy = busy;
+      window_state.mouse.effective_cursor()
+    };
     self.thread_executor.execute_in_thread(move || unsafe {
       let cursor = LoadCursorW(HMODULE::default(), cursor.to_windows_cursor()).unwrap_or_default();
       SetCursor(cursor);
@@ -470,6 +564,35 @@ impl Window {
     rx.recv().unwrap().ok();
   }
 
+  #[inline]
+  pub fn set_cursor_grab_and_visibility(
+    &self,
+    mode: CursorGrabMode,
+    visible: bool,
+  ) -> Result<(), ExternalError> {
+    let grab = match mode {
+      CursorGrabMode::None => false,
+      CursorGrabMode::Confined => true,
+      CursorGrabMode::Locked => return Err(ExternalError::NotSupported(NotSupportedError::new())),
+    };
+    let window = self.window.0 .0 as isize;
+    let window_state = Arc::clone(&self.window_state);
+    let (tx, rx) = channel::unbounded();
+
+    self.thread_executor.execute_in_thread(move || {
+      let result = window_state
+        .lock()
+        .mouse
+        .set_cursor_flags(HWND(window as _), |f| {
+          f.set(CursorFlags::HIDDEN, !visible);
+          f.set(CursorFlags::GRABBED, grab);
+        })
+        .map_err(|e| ExternalError::Os(os_error!(OsError::IoError(e))));
+      let _ = tx.send(result);
+    });
+    rx.recv().unwrap()
+  }
+
   #[inline]
   pub fn cursor_position(&self) -> Result<PhysicalPosition<f64>, ExternalError> {
     util::cursor_position().map_err(Into::into)
@@ -832,6 +955,18 @@ impl Window {
     });
   }
 
+  #[inline]
+  pub fn set_force_above_fullscreen(&self, force_above_fullscreen: bool) {
+    let window = self.window.0 .0 as isize;
+    let window_state = Arc::clone(&self.window_state);
+
+    self.thread_executor.execute_in_thread(move || {
+      WindowState::set_window_flags(window_state.lock(), HWND(window as _), |f| {
+        f.set(WindowFlags::FORCE_ABOVE_FULLSCREEN, force_above_fullscreen)
+      });
+    });
+  }
+
   pub fn set_rtl(&self, rtl: bool) {
     let window = self.window.0 .0 as isize;
     let window_state = Arc::clone(&self.window_state);
@@ -1078,6 +1213,10 @@ unsafe fn init<T: 'static>(
   );
   window_flags.set(WindowFlags::ALWAYS_ON_BOTTOM, attributes.always_on_bottom);
   window_flags.set(WindowFlags::ALWAYS_ON_TOP, attributes.always_on_top);
+  window_flags.set(
+    WindowFlags::FORCE_ABOVE_FULLSCREEN,
+    attributes.force_above_fullscreen,
+  );
   window_flags.set(
     WindowFlags::NO_BACK_BUFFER,
     pl_attribs.no_redirection_bitmap,
@@ -1219,6 +1358,25 @@ unsafe fn init<T: 'static>(
       .clamp(desired_size, win.scale_factor());
     win.set_inner_size(size);
 
+    // Position the window before it is shown so it doesn't jump, the outer size is only known
+    // once the inner size has been applied.
+    if attributes.center {
+      if let Some(monitor) = monitor::primary_monitor() {
+        let monitor_position = monitor.position();
+        let monitor_size = monitor.size();
+        let outer_size = win.outer_size();
+        win.set_outer_position(
+          PhysicalPosition::new(
+            monitor_position.x + (monitor_size.width as i32 - outer_size.width as i32) / 2,
+            monitor_position.y + (monitor_size.height as i32 - outer_size.height as i32) / 2,
+          )
+          .into(),
+        );
+      }
+    } else if let Some(position) = attributes.position {
+      win.set_outer_position(position);
+    }
+
     if attributes.maximized {
       // Need to set MAXIMIZED after setting `inner_size` as
       // `Window::set_inner_size` changes MAXIMIZED to false.
@@ -1233,10 +1391,6 @@ unsafe fn init<T: 'static>(
   win.set_visible(attributes.visible);
   win.set_closable(attributes.closable);
 
-  if let Some(position) = attributes.position {
-    win.set_outer_position(position);
-  }
-
   Ok(win)
 }
 
@@ -3,11 +3,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-  dpi::PhysicalPosition,
+  dpi::{PhysicalPosition, Size},
   icon::Icon,
   keyboard::ModifiersState,
-  platform_impl::platform::{event_loop, minimal_ime::MinimalIme, util},
-  window::{CursorIcon, Fullscreen, Theme, WindowAttributes, WindowSizeConstraints, RGBA},
+  platform_impl::platform::{event_loop, minimal_ime::MinimalIme, monitor::MonitorHandle, util},
+  window::{
+    CursorIcon, Fullscreen, ResizeBehavior, Theme, WindowAttributes, WindowSizeConstraints, RGBA,
+  },
 };
 use parking_lot::MutexGuard;
 use std::io;
@@ -24,12 +26,22 @@ pub struct WindowState {
   /// Used by `WM_GETMINMAXINFO`.
   pub size_constraints: WindowSizeConstraints,
 
+  /// Used by `WM_SIZING`.
+  pub resize_behavior: ResizeBehavior,
+  pub resize_increments: Option<Size>,
+
+  /// Used by `WM_NCHITTEST`, in logical pixels.
+  pub resize_border_width: Option<u32>,
+
   pub window_icon: Option<Icon>,
   pub taskbar_icon: Option<Icon>,
 
   pub saved_window: Option<SavedWindow>,
   pub scale_factor: f64,
 
+  /// Used by `WM_WINDOWPOSCHANGED` to detect moves to another monitor.
+  pub current_monitor: Option<MonitorHandle>,
+
   pub dragging: bool,
 
   pub skip_taskbar: bool,
@@ -61,6 +73,7 @@ pub struct SavedWindow {
 #[derive(Clone)]
 pub struct MouseProperties {
   pub cursor: CursorIcon,
+  pub busy: bool,
   pub capture_count: u32,
   cursor_flags: CursorFlags,
   pub last_position: Option<PhysicalPosition<f64>>,
@@ -117,6 +130,9 @@ bitflags! {
 
         const RIGHT_TO_LEFT_LAYOUT = 1 << 22;
 
+        /// Keep an `ALWAYS_ON_TOP` window above fullscreen apps instead of yielding to them.
+        const FORCE_ABOVE_FULLSCREEN = 1 << 23;
+
         const EXCLUSIVE_FULLSCREEN_OR_MASK = WindowFlags::ALWAYS_ON_TOP.bits();
     }
 }
@@ -133,6 +149,7 @@ impl WindowState {
     WindowState {
       mouse: MouseProperties {
         cursor: CursorIcon::default(),
+        busy: false,
         capture_count: 0,
         cursor_flags: CursorFlags::empty(),
         last_position: None,
@@ -140,12 +157,18 @@ impl WindowState {
 
       size_constraints: attributes.inner_size_constraints,
 
+      resize_behavior: ResizeBehavior::default(),
+      resize_increments: attributes.resize_increments,
+      resize_border_width: None,
+
       window_icon: attributes.window_icon.clone(),
       taskbar_icon,
 
       saved_window: None,
       scale_factor,
 
+      current_monitor: None,
+
       dragging: false,
 
       skip_taskbar: false,
@@ -206,6 +229,15 @@ impl WindowState {
 }
 
 impl MouseProperties {
+  /// The cursor to show in the client area, accounting for the busy cursor.
+  pub fn effective_cursor(&self) -> CursorIcon {
+    if self.busy {
+      CursorIcon::Wait
+    } else {
+      self.cursor
+    }
+  }
+
   pub fn cursor_flags(&self) -> CursorFlags {
     self.cursor_flags
   }
@@ -320,7 +352,10 @@ impl WindowFlags {
       }
     }
 
-    if diff.contains(WindowFlags::ALWAYS_ON_TOP) {
+    if diff.contains(WindowFlags::ALWAYS_ON_TOP)
+      || (diff.contains(WindowFlags::FORCE_ABOVE_FULLSCREEN)
+        && new.contains(WindowFlags::ALWAYS_ON_TOP))
+    {
       unsafe {
         let _ = SetWindowPos(
           window,
@@ -336,6 +371,15 @@ impl WindowFlags {
         );
         let _ = InvalidateRgn(window, HRGN::default(), false);
       }
+
+      // Only windows that yield to fullscreen apps need to know about them,
+      // see `APP_BAR_MSG_ID`.
+      let yields_to_fullscreen = new.contains(WindowFlags::ALWAYS_ON_TOP)
+        && !new.contains(WindowFlags::FORCE_ABOVE_FULLSCREEN);
+      util::set_app_bar_callback(
+        window,
+        yields_to_fullscreen.then(|| *event_loop::APP_BAR_MSG_ID),
+      );
     }
 
     if diff.contains(WindowFlags::ALWAYS_ON_BOTTOM) {
@@ -142,6 +142,11 @@ pub struct WindowAttributes {
   /// The window size constraints
   pub inner_size_constraints: WindowSizeConstraints,
 
+  /// The increments the window's inner size snaps to while the user resizes it.
+  ///
+  /// The default is `None`.
+  pub resize_increments: Option<Size>,
+
   /// The desired position of the window. If this is `None`, some platform-specific position
   /// will be chosen.
   ///
@@ -168,6 +173,18 @@ pub struct WindowAttributes {
   /// [`Window::set_outer_position`]: crate::window::Window::set_outer_position
   pub position: Option<Position>,
 
+  /// Whether the window should be placed at the center of the primary monitor, this takes
+  /// precedence over `position`.
+  ///
+  /// The whole window, including its decorations, is centered once it has its final size.
+  ///
+  /// The default is `false`.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux(Wayland) / iOS / Android**: Unsupported.
+  pub center: bool,
+
   /// Whether the window is resizable or not.
   ///
   /// The default is `true`.
@@ -235,6 +252,11 @@ pub struct WindowAttributes {
   /// The default is `false`.
   pub always_on_bottom: bool,
 
+  /// Whether an always on top window should stay above fullscreen apps.
+  ///
+  /// The default is `false`.
+  pub force_above_fullscreen: bool,
+
   /// The window icon.
   ///
   /// The default is `None`.
@@ -278,7 +300,9 @@ impl Default for WindowAttributes {
     WindowAttributes {
       inner_size: None,
       inner_size_constraints: Default::default(),
+      resize_increments: None,
       position: None,
+      center: false,
       resizable: true,
       minimizable: true,
       maximizable: true,
@@ -291,6 +315,7 @@ impl Default for WindowAttributes {
       decorations: true,
       always_on_top: false,
       always_on_bottom: false,
+      force_above_fullscreen: false,
       window_icon: None,
       preferred_theme: None,
       focused: true,
@@ -358,6 +383,15 @@ impl WindowBuilder {
     self
   }
 
+  /// Sets the increments the window's inner size snaps to while the user resizes it.
+  ///
+  /// See [`Window::set_resize_increments`] for details.
+  #[inline]
+  pub fn with_resize_increments<S: Into<Size>>(mut self, increments: S) -> Self {
+    self.window.resize_increments = Some(increments.into());
+    self
+  }
+
   /// Sets a desired initial position for the window.
   ///
   /// See [`WindowAttributes::position`] for details.
@@ -366,6 +400,18 @@ impl WindowBuilder {
   #[inline]
   pub fn with_position<P: Into<Position>>(mut self, position: P) -> Self {
     self.window.position = Some(position.into());
+    self.window.center = false;
+    self
+  }
+
+  /// Places the window at the center of the primary monitor.
+  ///
+  /// See [`WindowAttributes::center`] for details.
+  ///
+  /// [`WindowAttributes::center`]: crate::window::WindowAttributes::center
+  #[inline]
+  pub fn with_center_screen(mut self) -> Self {
+    self.window.center = true;
     self
   }
 
@@ -499,6 +545,17 @@ impl WindowBuilder {
     self
   }
 
+  /// Sets whether or not an always on top window stays above fullscreen apps.
+  ///
+  /// See [`Window::set_force_above_fullscreen`] for details.
+  ///
+  /// [`Window::set_force_above_fullscreen`]: crate::window::Window::set_force_above_fullscreen
+  #[inline]
+  pub fn with_force_above_fullscreen(mut self, force_above_fullscreen: bool) -> Self {
+    self.window.force_above_fullscreen = force_above_fullscreen;
+    self
+  }
+
   /// Sets the window icon.
   ///
   /// See [`Window::set_window_icon`] for details.
@@ -651,10 +708,63 @@ impl Window {
   pub fn request_redraw(&self) {
     self.window.request_redraw()
   }
+
+  /// Forces the window to repaint immediately instead of waiting for the next event loop
+  /// iteration.
+  ///
+  /// This is meant for tests and automation tools that need the window contents to be up to date
+  /// synchronously, applications should use [`Window::request_redraw`] instead.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows / macOS:** A `RedrawRequested` event is emitted before this returns, unless it's
+  ///   called from within the event handler, in which case it's emitted once the handler returns.
+  /// - **Linux:** Flushes GTK's pending paints, the `RedrawRequested` event is still emitted on
+  ///   the next event loop iteration.
+  /// - **iOS / Android:** Unsupported.
+  #[inline]
+  pub fn force_redraw_now(&self) {
+    self.window.force_redraw_now()
+  }
 }
 
 /// Position and size functions.
 impl Window {
+  /// Converts a position relative to the top-left hand corner of the desktop into a position
+  /// relative to the top-left hand corner of the window's client area.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS:** Can only be called on the main thread. The client area is the window's
+  ///   [safe area], as in [`Window::inner_position`].
+  /// - **Android:** Returns `position` unchanged.
+  ///
+  /// [safe area]: https://developer.apple.com/documentation/uikit/uiview/2891103-safeareainsets?language=objc
+  #[inline]
+  pub fn screen_to_window(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    self.window.screen_to_window(position)
+  }
+
+  /// Converts a position relative to the top-left hand corner of the window's client area into
+  /// a position relative to the top-left hand corner of the desktop.
+  ///
+  /// This is the inverse of [`Window::screen_to_window`].
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS:** Can only be called on the main thread.
+  /// - **Android:** Returns `position` unchanged.
+  #[inline]
+  pub fn window_to_screen(
+    &self,
+    position: PhysicalPosition<i32>,
+  ) -> Result<PhysicalPosition<i32>, ExternalError> {
+    self.window.window_to_screen(position)
+  }
+
   /// Returns the position of the top-left hand corner of the window's client area relative to the
   /// top-left hand corner of the desktop.
   ///
@@ -708,6 +818,21 @@ impl Window {
     self.window.set_outer_position(position.into())
   }
 
+  /// Modifies the position of the window like [`Window::set_outer_position`], but reports
+  /// failures instead of ignoring them.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS:** Can only be called on the main thread.
+  /// - **Android / Linux(Wayland):** Always returns an [`OsError`], as windows can't be moved.
+  #[inline]
+  pub fn set_outer_position_with_error<P: Into<Position>>(
+    &self,
+    position: P,
+  ) -> Result<(), OsError> {
+    self.window.set_outer_position_with_error(position.into())
+  }
+
   /// Returns the physical size of the window's client area.
   ///
   /// The client area is the content of the window, excluding the title bar and borders.
@@ -752,6 +877,9 @@ impl Window {
 
   /// Sets a minimum dimension size for the window.
   ///
+  /// Passing `None` removes the constraint and restores the platform's default minimum size,
+  /// it doesn't allow the window to shrink to zero.
+  ///
   /// ## Platform-specific
   ///
   /// - **iOS / Android:** Unsupported.
@@ -770,6 +898,22 @@ impl Window {
     self.window.set_max_inner_size(max_size.map(|s| s.into()))
   }
 
+  /// Sets the increments the window's inner size snaps to while the user resizes it, which is
+  /// useful for grid-based content such as terminals. `None` removes the increments.
+  ///
+  /// This is a hint, window managers and compositors may ignore it.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS:** The increments apply to the window's frame.
+  /// - **iOS / Android:** Unsupported.
+  #[inline]
+  pub fn set_resize_increments<S: Into<Size>>(&self, increments: Option<S>) {
+    self
+      .window
+      .set_resize_increments(increments.map(|s| s.into()))
+  }
+
   /// Sets inner size constraints for the window.
   ///
   /// ## Platform-specific
@@ -870,6 +1014,32 @@ impl Window {
     self.window.set_resizable(resizable)
   }
 
+  /// Sets which part of the window stays in place while the user resizes it.
+  ///
+  /// See [`ResizeBehavior`] for the available anchors.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Linux / iOS / Android:** Unsupported.
+  #[inline]
+  pub fn set_resize_behavior(&self, behavior: ResizeBehavior) {
+    self.window.set_resize_behavior(behavior)
+  }
+
+  /// Sets the width of the border that can be dragged to resize an undecorated window, in
+  /// logical pixels.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** Defaults to the system's resize frame width.
+  /// - **Linux:** Defaults to `5`.
+  /// - **macOS:** Unsupported, the resize area of undecorated windows is managed by AppKit.
+  /// - **iOS / Android:** Unsupported.
+  #[inline]
+  pub fn set_resize_border_width(&self, width: u32) {
+    self.window.set_resize_border_width(width)
+  }
+
   /// Sets whether the window is minimizable or not.
   ///
   /// ## Platform-specific
@@ -1063,14 +1233,37 @@ impl Window {
 
   /// Change whether or not the window will always be on top of other windows.
   ///
+  /// By default, an always on top window yields to fullscreen apps: it moves below them while
+  /// they are shown and goes back on top once they are closed. Use
+  /// [`Window::set_force_above_fullscreen`] to keep it above them instead.
+  ///
   /// ## Platform-specific
   ///
+  /// - **Linux:** Whether the window yields to fullscreen apps is up to the window manager, most
+  ///   of them keep a focused fullscreen window above the always on top ones.
   /// - **iOS / Android:** Unsupported.
   #[inline]
   pub fn set_always_on_top(&self, always_on_top: bool) {
     self.window.set_always_on_top(always_on_top)
   }
 
+  /// Change whether or not an always on top window stays above fullscreen apps, e.g. for screen
+  /// annotation tools. This has no effect until [`Window::set_always_on_top`] is enabled.
+  ///
+  /// The default is `false`, see [`Window::set_always_on_top`].
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **macOS:** The window joins all spaces, including the ones of fullscreen apps, even when it
+  ///   isn't always on top.
+  /// - **Linux / iOS / Android:** Unsupported.
+  #[inline]
+  pub fn set_force_above_fullscreen(&self, force_above_fullscreen: bool) {
+    self
+      .window
+      .set_force_above_fullscreen(force_above_fullscreen)
+  }
+
   /// Sets the window icon. On Windows and Linux, this is typically the small icon in the top-left
   /// corner of the title bar.
   ///
@@ -1144,9 +1337,13 @@ impl Window {
 
   /// Sets the theme for this window.
   ///
+  /// Emits [`WindowEvent::ThemeChanged`](crate::event::WindowEvent::ThemeChanged) if the
+  /// resulting theme differs from the current one.
+  ///
   /// ## Platform-specific
   ///
-  /// - **Linux / macOS**: Theme is app-wide and not specific to this window.
+  /// - **Linux / macOS**: Theme is app-wide and not specific to this window, every window whose
+  ///   theme changed receives the event.
   /// - **iOS / Android:** Unsupported.
   #[inline]
   pub fn set_theme(&self, #[allow(unused)] theme: Option<Theme>) {
@@ -1206,6 +1403,19 @@ impl Window {
     self.window.set_cursor_icon(cursor);
   }
 
+  /// Shows the system busy cursor over the window while `busy` is `true`.
+  ///
+  /// This takes precedence over the icon set with [`Window::set_cursor_icon`], which is restored
+  /// once `busy` is set back to `false`.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **iOS / Android:** Unsupported.
+  #[inline]
+  pub fn set_busy_cursor(&self, busy: bool) {
+    self.window.set_busy_cursor(busy);
+  }
+
   /// Changes the position of the cursor in window coordinates.
   ///
   /// ## Platform-specific
@@ -1245,6 +1455,27 @@ impl Window {
     self.window.set_cursor_visible(visible)
   }
 
+  /// Grabs or releases the cursor and changes its visibility in one call.
+  ///
+  /// Both settings are applied together, so there is no frame where the cursor is grabbed and
+  /// still visible. If `mode` isn't supported, nothing is changed and an
+  /// [`ExternalError::NotSupported`] is returned.
+  ///
+  /// ## Platform-specific
+  ///
+  /// - **Windows:** [`CursorGrabMode::Locked`] is unsupported.
+  /// - **macOS:** [`CursorGrabMode::Confined`] is unsupported.
+  /// - **Linux:** Only [`CursorGrabMode::None`] is supported.
+  /// - **iOS / Android:** Always returns an [`ExternalError::NotSupported`].
+  #[inline]
+  pub fn set_cursor_grab_and_visibility(
+    &self,
+    mode: CursorGrabMode,
+    visible: bool,
+  ) -> Result<(), ExternalError> {
+    self.window.set_cursor_grab_and_visibility(mode, visible)
+  }
+
   /// Moves the window with the left mouse button until the button is released.
   ///
   /// There's no guarantee that this will work unless the left mouse button was pressed
@@ -1338,7 +1569,8 @@ impl Window {
 
   /// Returns the primary monitor of the system.
   ///
-  /// Returns `None` if it can't identify any monitor as a primary one.
+  /// Returns `None` if it can't identify any monitor as a primary one, e.g. when no monitor is
+  /// attached.
   ///
   /// This is the same as `EventLoopWindowTarget::primary_monitor`, and is provided for convenience.
   ///
@@ -1456,6 +1688,19 @@ impl Default for CursorIcon {
   }
 }
 
+/// Describes how the cursor is grabbed, see [`Window::set_cursor_grab_and_visibility`].
+#[non_exhaustive]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CursorGrabMode {
+  /// The cursor isn't grabbed.
+  #[default]
+  None,
+  /// The cursor can move freely but can't leave the window.
+  Confined,
+  /// The cursor is locked in place inside the window.
+  Locked,
+}
+
 /// Fullscreen modes.
 #[non_exhaustive]
 #[allow(clippy::large_enum_variant)]
@@ -1611,6 +1856,21 @@ impl WindowSizeConstraints {
   }
 }
 
+/// Defines which part of the window stays in place while the user resizes it.
+///
+/// See [`Window::set_resize_behavior`].
+#[non_exhaustive]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ResizeBehavior {
+  /// The edge opposite to the one being dragged stays in place.
+  #[default]
+  Default,
+  /// The top-left corner stays in place, the window grows towards the bottom-right.
+  KeepTopLeft,
+  /// The center stays in place, the window grows evenly in all directions.
+  KeepCenter,
+}
+
 /// Defines the orientation that a window resize will be performed.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ResizeDirection {
@@ -1658,3 +1918,38 @@ pub(crate) fn hit_test(
     _ => None,
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{hit_test, ResizeDirection};
+
+  const WINDOW: (i32, i32, i32, i32) = (100, 100, 400, 300);
+  const BORDER: i32 = 10;
+
+  fn edge_at(x: i32, y: i32) -> Option<ResizeDirection> {
+    hit_test(WINDOW, 100 + x, 100 + y, BORDER, BORDER)
+  }
+
+  #[test]
+  fn hit_test_points_within_border_are_edges() {
+    for inset in [3, 9] {
+      assert_eq!(edge_at(inset, 100), Some(ResizeDirection::West));
+      assert_eq!(edge_at(150, inset), Some(ResizeDirection::North));
+      assert_eq!(edge_at(inset, inset), Some(ResizeDirection::NorthWest));
+      assert_eq!(edge_at(300 - inset, 100), Some(ResizeDirection::East));
+      assert_eq!(edge_at(150, 200 - inset), Some(ResizeDirection::South));
+      assert_eq!(
+        edge_at(300 - inset, 200 - inset),
+        Some(ResizeDirection::SouthEast)
+      );
+    }
+  }
+
+  #[test]
+  fn hit_test_points_past_border_are_not_edges() {
+    assert_eq!(edge_at(11, 100), None);
+    assert_eq!(edge_at(150, 11), None);
+    assert_eq!(edge_at(11, 11), None);
+    assert_eq!(edge_at(300 - 11, 200 - 11), None);
+  }
+}
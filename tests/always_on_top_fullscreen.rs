@@ -0,0 +1,59 @@
+// Copyright 2014-2021 The winit contributors
+// Copyright 2021-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(target_os = "windows")]
+
+use tao::{
+  event_loop::EventLoopBuilder,
+  platform::windows::{EventLoopBuilderExtWindows, WindowExtWindows},
+  window::{Window, WindowBuilder},
+};
+use windows::{
+  core::s,
+  Win32::{
+    Foundation::{HWND, LPARAM, WPARAM},
+    UI::{Shell::ABN_FULLSCREENAPP, WindowsAndMessaging::*},
+  },
+};
+
+fn is_topmost(window: &Window) -> bool {
+  let ex_style = unsafe { GetWindowLongW(HWND(window.hwnd() as _), GWL_EXSTYLE) } as u32;
+  ex_style & WS_EX_TOPMOST.0 != 0
+}
+
+// Simulates the shell notifying the window that a fullscreen app was opened or closed.
+fn notify_fullscreen_app(window: &Window, opened: bool) {
+  unsafe {
+    let msg = RegisterWindowMessageA(s!("Tao::AppBar"));
+    SendMessageW(
+      HWND(window.hwnd() as _),
+      msg,
+      WPARAM(ABN_FULLSCREENAPP as _),
+      LPARAM(opened as _),
+    );
+  }
+}
+
+#[test]
+fn always_on_top_yields_to_fullscreen_apps() {
+  let event_loop = EventLoopBuilder::<()>::new().with_any_thread(true).build();
+  let window = WindowBuilder::new()
+    .with_always_on_top(true)
+    .build(&event_loop)
+    .unwrap();
+  assert!(is_topmost(&window));
+
+  // By default the window moves below the fullscreen app and back on top once it is closed.
+  notify_fullscreen_app(&window, true);
+  assert!(!is_topmost(&window));
+  assert!(window.is_always_on_top());
+  notify_fullscreen_app(&window, false);
+  assert!(is_topmost(&window));
+
+  window.set_force_above_fullscreen(true);
+  notify_fullscreen_app(&window, true);
+  assert!(is_topmost(&window));
+  notify_fullscreen_app(&window, false);
+  assert!(is_topmost(&window));
+}
@@ -0,0 +1,29 @@
+// Copyright 2014-2021 The winit contributors
+// Copyright 2021-2023 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(any(target_os = "windows", target_os = "linux"))]
+
+use tao::event_loop::EventLoopBuilder;
+#[cfg(target_os = "linux")]
+use tao::platform::unix::EventLoopBuilderExtUnix;
+#[cfg(target_os = "windows")]
+use tao::platform::windows::EventLoopBuilderExtWindows;
+
+// The `None` case without any monitor is covered by the backends' own tests, this checks that
+// querying the primary monitor doesn't panic and never fabricates a handle.
+#[test]
+fn primary_monitor_is_an_available_monitor() {
+  // GTK can't be initialized at all without a display server.
+  #[cfg(target_os = "linux")]
+  if std::env::var_os("DISPLAY").is_none() && std::env::var_os("WAYLAND_DISPLAY").is_none() {
+    return;
+  }
+
+  let event_loop = EventLoopBuilder::<()>::new().with_any_thread(true).build();
+  if let Some(primary_monitor) = event_loop.primary_monitor() {
+    assert!(event_loop
+      .available_monitors()
+      .any(|monitor| monitor == primary_monitor));
+  }
+}
@@ -7,7 +7,7 @@
 use serde::{Deserialize, Serialize};
 use tao::{
   dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize},
-  event::{ElementState, MouseButton, MouseScrollDelta, TouchPhase},
+  event::{ElementState, MouseButton, MouseScrollDelta, OcclusionState, TouchPhase},
   keyboard::{Key, KeyCode, KeyLocation, ModifiersState},
   window::CursorIcon,
 };
@@ -30,6 +30,7 @@ fn events_serde() {
   needs_serde::<KeyCode>();
   needs_serde::<KeyLocation>();
   needs_serde::<ModifiersState>();
+  needs_serde::<OcclusionState>();
 }
 
 #[test]
@@ -5,6 +5,15 @@
 #[allow(dead_code)]
 fn needs_sync<T: Sync>() {}
 
+#[test]
+fn event_loop_proxy_sync() {
+  #[allow(dead_code)]
+  fn is_sync<T: 'static + Send>() {
+    // ensures that `EventLoopProxy` implements `Sync`
+    needs_sync::<tao::event_loop::EventLoopProxy<T>>();
+  }
+}
+
 #[test]
 fn window_sync() {
   // ensures that `Window` implements `Sync`